@@ -0,0 +1,224 @@
+//! Per-player statistics derived from `MatchDetails`: ACS, ADR, shot
+//! distribution, KAST, opening duels, multi-kills, clutches and trades.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::models::match_data::{Kill, MatchDetails};
+
+/// A kill counts as a trade if it lands this soon after the enemy killed a teammate.
+pub const TRADE_WINDOW_MS: u64 = 3000;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DerivedStats {
+    pub puuid: String,
+    pub team_id: String,
+    pub character_id: String,
+    pub rounds_played: u32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub score: u32,
+    /// Average combat score.
+    pub acs: f64,
+    /// Average damage per round, against enemies only.
+    pub adr: f64,
+    pub damage_dealt: u32,
+    pub headshots: u32,
+    pub bodyshots: u32,
+    pub legshots: u32,
+    pub headshot_pct: f64,
+    pub bodyshot_pct: f64,
+    pub legshot_pct: f64,
+    /// Percentage of rounds with a kill, assist, survival or traded death.
+    pub kast: f64,
+    pub first_kills: u32,
+    pub first_deaths: u32,
+    pub multi_kills: MultiKills,
+    /// Rounds where the player was last alive on their team with an
+    /// opponent still standing; in a 1v1 both players attempt a clutch.
+    pub clutch_attempts: u32,
+    pub clutches_won: u32,
+    /// Kills on an enemy who had just killed a teammate.
+    pub trade_kills: u32,
+    /// Deaths avenged by a teammate within the trade window.
+    pub traded_deaths: u32,
+}
+
+/// Rounds with at least two kills, by kill count.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiKills {
+    pub double: u32,
+    pub triple: u32,
+    pub quadra: u32,
+    pub ace: u32,
+}
+
+impl MultiKills {
+    fn record(&mut self, kills: u32) {
+        match kills {
+            0 | 1 => {}
+            2 => self.double += 1,
+            3 => self.triple += 1,
+            4 => self.quadra += 1,
+            _ => self.ace += 1,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    damage: u32,
+    headshots: u32,
+    bodyshots: u32,
+    legshots: u32,
+    kast_rounds: u32,
+    first_kills: u32,
+    first_deaths: u32,
+    multi_kills: MultiKills,
+    clutch_attempts: u32,
+    clutches_won: u32,
+    trade_kills: u32,
+    traded_deaths: u32,
+}
+
+/// Stats for every non-observer player, in the match's player order.
+pub fn derived_stats(details: &MatchDetails) -> Vec<DerivedStats> {
+    let team_of: HashMap<&str, &str> = details.players.iter()
+        .map(|p| (p.subject.as_str(), p.team_id.as_str()))
+        .collect();
+    let enemies = |a: &str, b: &str| match (team_of.get(a), team_of.get(b)) {
+        (Some(x), Some(y)) => x != y,
+        _ => false,
+    };
+    let mut tallies: HashMap<&str, Tally> = HashMap::new();
+
+    for round in &details.round_results {
+        let mut kills: Vec<&Kill> = round.player_stats.iter().flat_map(|s| &s.kills).collect();
+        kills.sort_by_key(|k| k.round_time);
+
+        for stats in &round.player_stats {
+            let tally = tallies.entry(&stats.subject).or_default();
+            for hit in stats.damage.iter().filter(|d| enemies(&stats.subject, &d.receiver)) {
+                tally.damage += hit.damage;
+                tally.headshots += hit.headshots;
+                tally.bodyshots += hit.bodyshots;
+                tally.legshots += hit.legshots;
+            }
+        }
+
+        if let Some(first) = kills.first() {
+            if let Some(killer) = first.killer.as_deref() {
+                tallies.entry(killer).or_default().first_kills += 1;
+            }
+            tallies.entry(&first.victim).or_default().first_deaths += 1;
+        }
+
+        let mut kast: HashSet<&str> = round.player_stats.iter()
+            .map(|s| s.subject.as_str())
+            .filter(|p| !kills.iter().any(|k| k.victim == *p))
+            .collect();
+        let mut round_kills: HashMap<&str, u32> = HashMap::new();
+        // One kill can avenge several deaths but is only one trade kill.
+        let mut trading_kills: HashSet<usize> = HashSet::new();
+        for (i, kill) in kills.iter().enumerate() {
+            kast.extend(kill.assistants.iter().map(String::as_str));
+            let Some(killer) = kill.killer.as_deref() else { continue };
+            if !enemies(killer, &kill.victim) {
+                continue;
+            }
+            kast.insert(killer);
+            *round_kills.entry(killer).or_default() += 1;
+
+            // Did a teammate of the victim kill this killer shortly after?
+            let trade = kills.iter().enumerate().skip(i + 1)
+                .take_while(|(_, k)| k.round_time <= kill.round_time + TRADE_WINDOW_MS)
+                .find(|(_, k)| k.victim == killer
+                    && k.killer.as_deref().is_some_and(|t| t != kill.victim && !enemies(t, &kill.victim)));
+            if let Some((j, trader)) = trade.and_then(|(j, k)| k.killer.as_deref().map(|t| (j, t))) {
+                kast.insert(&kill.victim);
+                tallies.entry(&kill.victim).or_default().traded_deaths += 1;
+                if trading_kills.insert(j) {
+                    tallies.entry(trader).or_default().trade_kills += 1;
+                }
+            }
+        }
+        for player in kast {
+            tallies.entry(player).or_default().kast_rounds += 1;
+        }
+        for (player, count) in round_kills {
+            tallies.entry(player).or_default().multi_kills.record(count);
+        }
+
+        // Everyone left alone against at least one opponent, in player order.
+        let mut alive: Vec<&str> = round.player_stats.iter().map(|s| s.subject.as_str()).collect();
+        let mut clutchers: Vec<&str> = Vec::new();
+        for kill in &kills {
+            alive.retain(|p| *p != kill.victim);
+            for &player in &alive {
+                let team = team_of.get(player);
+                let teammates = alive.iter().filter(|q| team_of.get(*q) == team).count();
+                let opponents = alive.iter().filter(|q| enemies(player, q)).count();
+                if teammates == 1 && opponents > 0 && !clutchers.contains(&player) {
+                    clutchers.push(player);
+                }
+            }
+        }
+        for player in clutchers {
+            let tally = tallies.entry(player).or_default();
+            tally.clutch_attempts += 1;
+            if team_of.get(player) == Some(&round.winning_team.as_str()) {
+                tally.clutches_won += 1;
+            }
+        }
+    }
+
+    details.players.iter()
+        .filter(|p| !p.is_observer)
+        .map(|p| {
+            let tally = tallies.remove(p.subject.as_str()).unwrap_or_default();
+            let rounds = p.stats.rounds_played;
+            let shots = tally.headshots + tally.bodyshots + tally.legshots;
+            DerivedStats {
+                puuid: p.subject.clone(),
+                team_id: p.team_id.clone(),
+                character_id: p.character_id.clone(),
+                rounds_played: rounds,
+                kills: p.stats.kills,
+                deaths: p.stats.deaths,
+                assists: p.stats.assists,
+                score: p.stats.score,
+                acs: ratio(p.stats.score, rounds),
+                adr: ratio(tally.damage, rounds),
+                damage_dealt: tally.damage,
+                headshots: tally.headshots,
+                bodyshots: tally.bodyshots,
+                legshots: tally.legshots,
+                headshot_pct: percent(tally.headshots, shots),
+                bodyshot_pct: percent(tally.bodyshots, shots),
+                legshot_pct: percent(tally.legshots, shots),
+                kast: percent(tally.kast_rounds, rounds),
+                first_kills: tally.first_kills,
+                first_deaths: tally.first_deaths,
+                multi_kills: tally.multi_kills,
+                clutch_attempts: tally.clutch_attempts,
+                clutches_won: tally.clutches_won,
+                trade_kills: tally.trade_kills,
+                traded_deaths: tally.traded_deaths,
+            }
+        })
+        .collect()
+}
+
+/// Stats for a single player, if they played in the match.
+pub fn player_stats(details: &MatchDetails, puuid: &str) -> Option<DerivedStats> {
+    derived_stats(details).into_iter().find(|s| s.puuid == puuid)
+}
+
+pub(crate) fn ratio(total: u32, count: u32) -> f64 {
+    if count == 0 { 0.0 } else { total as f64 / count as f64 }
+}
+
+pub(crate) fn percent(part: u32, whole: u32) -> f64 {
+    ratio(part, whole) * 100.0
+}
@@ -1,8 +1,14 @@
+use crate::builder::{env_var, ENV_LOCKFILE};
 use crate::error::ValorantError;
-use std::path::PathBuf;
+use crate::log_watcher::default_log_path;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use base64::Engine;
 
-#[derive(Debug, Clone)]
+/// Refresh the access token this long before its `exp` claim is reached.
+pub const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LockfileData {
     pub port: u16,
     pub password: String,
@@ -11,8 +17,11 @@ pub struct LockfileData {
 
 impl LockfileData {
     pub fn read() -> Result<Self, ValorantError> {
-        let path = lockfile_path()?;
-        let content = std::fs::read_to_string(&path)
+        Self::read_from(&lockfile_path()?)
+    }
+
+    pub fn read_from(path: &std::path::Path) -> Result<Self, ValorantError> {
+        let content = std::fs::read_to_string(path)
             .map_err(|_| ValorantError::LockfileNotFound)?;
         
         let parts: Vec<&str> = content.trim().split(':').collect();
@@ -28,7 +37,12 @@ impl LockfileData {
     }
 }
 
-fn lockfile_path() -> Result<PathBuf, ValorantError> {
+/// `VAL_LOCKFILE` if set, otherwise the first existing default location.
+pub fn lockfile_path() -> Result<PathBuf, ValorantError> {
+    if let Some(path) = env_var(ENV_LOCKFILE) {
+        return Ok(PathBuf::from(path));
+    }
+
     let candidates = vec![
         PathBuf::from(r"C:\Riot Games\Riot Client\Config\lockfile"),
         dirs::data_local_dir()
@@ -46,14 +60,29 @@ pub struct RiotAuth {
     pub access_token: String,
     pub entitlements_token: String,
     pub puuid: String,
+    /// PD shard: `na`, `eu`, `ap`, `kr` or `pbe`.
     pub shard: String,
+    /// GLZ region: `na`, `latam`, `br`, `eu`, `ap` or `kr`.
     pub region: String,
+    /// Unix timestamp (seconds) from the access token's `exp` claim.
+    pub expires_at: u64,
 }
 
 impl RiotAuth {
     pub async fn fetch(client: &reqwest::Client, lockfile: &LockfileData) -> Result<Self, ValorantError> {
         let base = format!("{}://127.0.0.1:{}", lockfile.protocol, lockfile.port);
-        
+        Self::fetch_from(client, &base, lockfile).await
+    }
+
+    /// Like `fetch`, but against an explicit local API base URL.
+    pub async fn fetch_from(client: &reqwest::Client, base: &str, lockfile: &LockfileData) -> Result<Self, ValorantError> {
+        let mut auth = Self::fetch_tokens(client, base, lockfile).await?;
+        (auth.region, auth.shard) = resolve_region(client, base, lockfile, None).await?;
+        Ok(auth)
+    }
+
+    /// Tokens only; `region` and `shard` are left empty.
+    pub(crate) async fn fetch_tokens(client: &reqwest::Client, base: &str, lockfile: &LockfileData) -> Result<Self, ValorantError> {
         let resp: serde_json::Value = client
             .get(format!("{}/entitlements/v1/token", base))
             .basic_auth("riot", Some(&lockfile.password))
@@ -67,13 +96,32 @@ impl RiotAuth {
         let puuid = resp["subject"].as_str()
             .ok_or_else(|| ValorantError::AuthFailed("missing subject".into()))?.to_string();
 
-        let (shard, region) = parse_region_from_token(&access_token)?;
+        let claims = decode_jwt_payload(&access_token)?;
+        let expires_at = claims.get("exp")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| ValorantError::AuthFailed("missing exp claim".into()))?;
+
+        Ok(RiotAuth {
+            access_token,
+            entitlements_token,
+            puuid,
+            shard: String::new(),
+            region: String::new(),
+            expires_at,
+        })
+    }
 
-        Ok(RiotAuth { access_token, entitlements_token, puuid, shard, region })
+    /// True if the access token expires within `margin` from now.
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.expires_at <= now + margin.as_secs()
     }
 }
 
-fn parse_region_from_token(token: &str) -> Result<(String, String), ValorantError> {
+fn decode_jwt_payload(token: &str) -> Result<serde_json::Value, ValorantError> {
     let parts: Vec<&str> = token.split('.').collect();
     if parts.len() < 2 {
         return Err(ValorantError::AuthFailed("invalid JWT".into()));
@@ -90,26 +138,78 @@ fn parse_region_from_token(token: &str) -> Result<(String, String), ValorantErro
         .decode(&padded)
         .map_err(|_| ValorantError::AuthFailed("JWT decode failed".into()))?;
 
-    let json: serde_json::Value = serde_json::from_slice(&payload)?;
+    Ok(serde_json::from_slice(&payload)?)
+}
 
-    // Try multiple known fields where region appears
-    let shard = json.pointer("/acct/country")
-        .or_else(|| json.get("region"))
-        .or_else(|| json.get("shard"))
-        .and_then(|v| v.as_str())
-        .map(map_region_to_shard)
-        .unwrap_or_else(|| "eu".to_string());
+/// Resolve `(region, shard)` from the Riot Client's `/riotclient/region-locale`,
+/// falling back to the last GLZ URL near the end of `ShooterGame.log`.
+/// Called on connect and reconnect only; token refreshes keep the result.
+/// `log_path` defaults to `default_log_path()`.
+pub(crate) async fn resolve_region(
+    client: &reqwest::Client,
+    base: &str,
+    lockfile: &LockfileData,
+    log_path: Option<&Path>,
+) -> Result<(String, String), ValorantError> {
+    let locale = region_locale(client, base, lockfile).await;
+    if let Some(found) = locale.as_deref().ok().and_then(region_and_shard) {
+        return Ok(found);
+    }
+    let from_log = match log_path.map(Path::to_path_buf).or_else(|| default_log_path().ok()) {
+        Some(path) => region_from_log(&path).await,
+        None => None,
+    };
+    if let Some(found) = from_log {
+        return Ok(found);
+    }
+    Err(match locale {
+        Ok(riot_region) => ValorantError::RegionUnknown(format!("unrecognised Riot region {:?}", riot_region)),
+        Err(e) => e,
+    })
+}
 
-    // region and shard are often the same for Valorant
-    Ok((shard.clone(), shard))
+async fn region_locale(client: &reqwest::Client, base: &str, lockfile: &LockfileData) -> Result<String, ValorantError> {
+    let resp: serde_json::Value = client
+        .get(format!("{}/riotclient/region-locale", base))
+        .basic_auth("riot", Some(&lockfile.password))
+        .send().await?
+        .json().await?;
+    resp["region"].as_str()
+        .map(str::to_string)
+        .ok_or_else(|| ValorantError::RegionUnknown("region-locale returned no region".into()))
 }
 
-fn map_region_to_shard(region: &str) -> String {
-    match region.to_lowercase().as_str() {
-        "euw" | "eune" | "eu" | "tr" | "ru" => "eu",
-        "na" | "us" | "br" | "latam" | "lan" | "las" => "na",
-        "ap" | "kr" | "jp" | "oce" | "sea" => "ap",
-        other => other,
-    }
-    .to_string()
+/// Only this much of the end of the log is scanned; it can grow to many MB.
+const LOG_TAIL_BYTES: u64 = 512 * 1024;
+
+/// Scan the end of the game log for the most recent `glz-{region}-1.{shard}.a.pvp.net` host.
+async fn region_from_log(path: &Path) -> Option<(String, String)> {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    let mut file = tokio::fs::File::open(path).await.ok()?;
+    let len = file.metadata().await.ok()?.len();
+    file.seek(std::io::SeekFrom::Start(len.saturating_sub(LOG_TAIL_BYTES))).await.ok()?;
+    let mut content = Vec::new();
+    file.read_to_end(&mut content).await.ok()?;
+    let content = String::from_utf8_lossy(&content);
+    let start = content.rfind("https://glz-")? + "https://glz-".len();
+    let host = content[start..].split(['/', ' ', ']', '"']).next()?;
+    let (region, rest) = host.split_once("-1.")?;
+    let shard = rest.strip_suffix(".a.pvp.net")?;
+    Some((region.to_string(), shard.to_string()))
+}
+
+/// Map a Riot Client region (e.g. `EUW`, `LA1`, `BR`) to Valorant's `(region, shard)`.
+pub fn region_and_shard(riot_region: &str) -> Option<(String, String)> {
+    let (region, shard) = match riot_region.to_lowercase().as_str() {
+        "na" | "na1" | "us" => ("na", "na"),
+        "latam" | "la1" | "la2" | "lan" | "las" => ("latam", "na"),
+        "br" | "br1" => ("br", "na"),
+        "pbe" | "pbe1" => ("na", "pbe"),
+        "eu" | "euw" | "euw1" | "eune" | "eun1" | "tr" | "tr1" | "ru" | "me1" => ("eu", "eu"),
+        "ap" | "jp" | "jp1" | "oc1" | "oce" | "sg2" | "sea" | "ph2" | "th2" | "tw2" | "vn2" => ("ap", "ap"),
+        "kr" | "kr1" => ("kr", "kr"),
+        _ => return None,
+    };
+    Some((region.to_string(), shard.to_string()))
 }
@@ -2,52 +2,100 @@ use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::{IntoResponse, sse::{Event, Sse}},
-    routing::{get, post},
+    routing::{delete, get, post},
     Json, Router,
 };
 use std::{collections::HashMap, convert::Infallible, sync::Arc};
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, RwLock};
 use tokio_stream::{wrappers::BroadcastStream, StreamExt};
 use tower_http::cors::CorsLayer;
 use serde_json::json;
-use val_local_api::{ValorantClient, ValorantError, LogWatcher, LogEvent};
+use val_local_api::{ConnectionState, GamePhase, ValorantClient, ValorantError, LogEvent, PhaseChange, PhaseState, PhaseTracker};
+use val_local_api::analytics::derived_stats;
+use val_local_api::models::chat::ChatMessage;
+use val_local_api::models::match_data::{MatchHistoryQuery, Queue};
+use val_local_api::models::party::{Accessibility, Party};
+use val_local_api::profile::PlayerProfile;
 
 #[derive(Clone)]
 struct AppState {
     client: Arc<ValorantClient>,
     log_tx: broadcast::Sender<LogEvent>,
+    phase: Arc<RwLock<PhaseState>>,
+    phase_tx: broadcast::Sender<PhaseChange>,
+    /// Destructive POST routes (lock agent, dodge, ...) only work when the
+    /// server was started with `--allow-actions` or `VAL_ALLOW_ACTIONS=1`.
+    allow_actions: bool,
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let client = ValorantClient::connect().await.expect("Failed to connect to Valorant");
+    client.watch_lockfile()?;
     
-    let (log_watcher, _rx) = LogWatcher::new();
+    let (log_watcher, _rx) = client.log_watcher();
     let log_tx = log_watcher.sender();
     log_watcher.start()?;
+
+    let (phase_tracker, _phase_rx) = PhaseTracker::new(client.clone());
+    let phase_tracker = phase_tracker.with_log_events(log_watcher.subscribe());
+    let phase_tx = phase_tracker.sender();
+    let phase = phase_tracker.start();
+    
+    let allow_actions = std::env::args().any(|a| a == "--allow-actions")
+        || std::env::var("VAL_ALLOW_ACTIONS").is_ok_and(|v| v == "1");
     
     let state = AppState {
         client: Arc::new(client),
         log_tx,
+        phase,
+        phase_tx,
+        allow_actions,
     };
 
     let app = Router::new()
         .route("/status", get(status_handler))
         .route("/auth", get(auth_handler))
         .route("/pregame/match", get(pregame_match_handler))
+        .route("/pregame/select/:agent_id", post(pregame_select_handler))
+        .route("/pregame/lock/:agent_id", post(pregame_lock_handler))
+        .route("/pregame/quit", post(pregame_quit_handler))
+        .route("/party", get(party_handler))
+        .route("/party/accessibility", post(party_accessibility_handler))
+        .route("/party/queue", post(party_queue_handler))
+        .route("/party/invite/:name/:tag", post(party_invite_handler))
+        .route("/party/matchmaking/join", post(party_join_matchmaking_handler))
+        .route("/party/matchmaking/leave", post(party_leave_matchmaking_handler))
+        .route("/party/ready", post(party_ready_handler))
         .route("/coregame/match", get(coregame_match_handler))
         .route("/coregame/loadouts", get(coregame_loadouts_handler))
+        .route("/live/lobby", get(live_lobby_handler))
+        .route("/presences", get(presences_handler))
+        .route("/friends", get(friends_handler))
+        .route("/friends/:puuid", delete(remove_friend_handler))
+        .route("/friends/requests", get(friend_requests_handler))
+        .route("/friends/requests/:name/:tag", post(send_friend_request_handler))
+        .route("/chat/conversations", get(conversations_handler))
+        .route("/chat/history", get(chat_history_handler))
+        .route("/chat/send", post(chat_send_handler))
+        .route("/chat/events", get(chat_events_handler))
         .route("/pd/history", get(history_handler))
-        .route("/pd/mmr/{puuid}", get(mmr_handler))
-        .route("/pd/match/{match_id}", get(match_detail_handler))
+        .route("/pd/mmr/:puuid", get(mmr_handler))
+        .route("/pd/match/:match_id", get(match_detail_handler))
+        .route("/pd/match/:match_id/stats", get(match_stats_handler))
+        .route("/pd/profile/:puuid", get(profile_handler))
         .route("/pd/names", post(names_handler))
-        .route("/pd/lookup/{name}/{tag}", get(lookup_handler))
+        .route("/pd/lookup/:name/:tag", get(lookup_handler))
         .route("/log/events", get(log_events_handler))
+        .route("/phase/events", get(phase_events_handler))
         .with_state(state)
         .layer(CorsLayer::permissive());
 
     let listener = tokio::net::TcpListener::bind("127.0.0.1:9922").await.unwrap();
     println!("Server running on http://127.0.0.1:9922");
+    if allow_actions {
+        println!("Pregame/party actions enabled");
+    }
     axum::serve(listener, app).await.unwrap();
 
     Ok(())
@@ -58,27 +106,28 @@ fn error_response(e: ValorantError) -> axum::response::Response {
         ValorantError::NotInMatch => StatusCode::NOT_FOUND,
         ValorantError::LockfileNotFound => StatusCode::SERVICE_UNAVAILABLE,
         ValorantError::AuthFailed(_) => StatusCode::UNAUTHORIZED,
+        ValorantError::ApiError { status: 404, .. } => StatusCode::NOT_FOUND,
         _ => StatusCode::INTERNAL_SERVER_ERROR,
     };
     (status, Json(json!({ "error": e.to_string() }))).into_response()
 }
 
 async fn status_handler(State(state): State<AppState>) -> Json<serde_json::Value> {
-    let puuid = state.client.puuid().await;
-    
-    if state.client.pregame_player(&puuid).await.is_ok() {
-        return Json(json!({ "running": true, "phase": "pregame" }));
+    let connection = state.client.connection_state().await;
+    if connection != ConnectionState::Connected {
+        return Json(json!({ "running": false, "connection": connection }));
     }
-    if state.client.coregame_player(&puuid).await.is_ok() {
-        return Json(json!({ "running": true, "phase": "ingame" }));
-    }    Json(json!({ "running": true, "phase": "menu" }))
+
+    let phase = state.phase.read().await.clone();
+    let running = phase.phase != GamePhase::NotRunning;
+    Json(json!({ "running": running, "connection": connection, "phase": phase.phase, "match_id": phase.match_id }))
 }
 
 async fn auth_handler(State(state): State<AppState>) -> Json<serde_json::Value> {
     let auth = state.client.get_auth().await;
     let puuid = auth.puuid.clone();
     
-    let names: Option<Vec<val_local_api::NameEntry>> = state.client.resolve_names(&[puuid.clone()]).await.ok();
+    let names: Option<Vec<val_local_api::NameEntry>> = state.client.resolve_names(std::slice::from_ref(&puuid)).await.ok();
     let (name, tag) = names
         .and_then(|mut n| n.pop())
         .map(|e| (e.name, e.tag))
@@ -105,6 +154,169 @@ async fn pregame_match_handler(State(state): State<AppState>) -> impl IntoRespon
     }
 }
 
+fn actions_disabled() -> axum::response::Response {
+    (StatusCode::FORBIDDEN, Json(json!({
+        "error": "Actions are disabled — start the server with --allow-actions"
+    }))).into_response()
+}
+
+/// Match ID of the agent select the local player is in.
+async fn own_pregame_match(state: &AppState) -> Result<String, axum::response::Response> {
+    let puuid = state.client.puuid().await;
+    match state.client.pregame_player(&puuid).await {
+        Ok(player) => Ok(player.match_id),
+        Err(ValorantError::NotInMatch) => Err((StatusCode::NOT_FOUND, Json(json!({"error": "Not in agent select"}))).into_response()),
+        Err(e) => Err(error_response(e)),
+    }
+}
+
+async fn pregame_select_handler(
+    State(state): State<AppState>,
+    Path(agent_id): Path<String>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let match_id = match own_pregame_match(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    match state.client.pregame_select_agent(&match_id, &agent_id).await {
+        Ok(data) => (StatusCode::OK, Json(json!(data))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn pregame_lock_handler(
+    State(state): State<AppState>,
+    Path(agent_id): Path<String>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let match_id = match own_pregame_match(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    match state.client.pregame_lock_agent(&match_id, &agent_id).await {
+        Ok(data) => (StatusCode::OK, Json(json!(data))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn pregame_quit_handler(State(state): State<AppState>) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let match_id = match own_pregame_match(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    match state.client.pregame_quit(&match_id).await {
+        Ok(()) => (StatusCode::OK, Json(json!({ "quit": match_id }))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Party ID of the local player.
+async fn own_party_id(state: &AppState) -> Result<String, axum::response::Response> {
+    let puuid = state.client.puuid().await;
+    state.client.party_player(&puuid).await
+        .map(|p| p.party_id)
+        .map_err(error_response)
+}
+
+fn party_response(result: Result<Party, ValorantError>) -> axum::response::Response {
+    match result {
+        Ok(data) => (StatusCode::OK, Json(json!(data))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn party_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let party_id = match own_party_id(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    party_response(state.client.party(&party_id).await)
+}
+
+#[derive(serde::Deserialize)]
+struct AccessibilityBody {
+    accessibility: Accessibility,
+}
+
+async fn party_accessibility_handler(
+    State(state): State<AppState>,
+    Json(body): Json<AccessibilityBody>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let party_id = match own_party_id(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    party_response(state.client.party_set_accessibility(&party_id, body.accessibility).await)
+}
+
+#[derive(serde::Deserialize)]
+struct QueueBody {
+    queue_id: String,
+}
+
+async fn party_queue_handler(
+    State(state): State<AppState>,
+    Json(body): Json<QueueBody>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let party_id = match own_party_id(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    party_response(state.client.party_change_queue(&party_id, &body.queue_id).await)
+}
+
+async fn party_invite_handler(
+    State(state): State<AppState>,
+    Path((name, tag)): Path<(String, String)>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let party_id = match own_party_id(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    party_response(state.client.party_invite_by_name(&party_id, &name, &tag).await)
+}
+
+async fn party_join_matchmaking_handler(State(state): State<AppState>) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let party_id = match own_party_id(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    party_response(state.client.party_join_matchmaking(&party_id).await)
+}
+
+async fn party_leave_matchmaking_handler(State(state): State<AppState>) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let party_id = match own_party_id(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    party_response(state.client.party_leave_matchmaking(&party_id).await)
+}
+
+#[derive(serde::Deserialize)]
+struct ReadyBody {
+    ready: bool,
+}
+
+async fn party_ready_handler(
+    State(state): State<AppState>,
+    Json(body): Json<ReadyBody>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    let party_id = match own_party_id(&state).await {
+        Ok(id) => id,
+        Err(resp) => return resp,
+    };
+    let puuid = state.client.puuid().await;
+    party_response(state.client.party_set_ready(&party_id, &puuid, body.ready).await)
+}
+
 async fn coregame_match_handler(State(state): State<AppState>) -> impl IntoResponse {
     let puuid = state.client.puuid().await;
     match state.client.coregame_player(&puuid).await {
@@ -129,6 +341,126 @@ async fn coregame_loadouts_handler(State(state): State<AppState>) -> impl IntoRe
     }
 }
 
+async fn live_lobby_handler(State(state): State<AppState>) -> impl IntoResponse {
+    match state.client.live_lobby().await {
+        Ok(data) => (StatusCode::OK, Json(json!(data))).into_response(),
+        Err(ValorantError::NotInMatch) => (StatusCode::NOT_FOUND, Json(json!({"error": "Not in match"}))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn presences_handler(State(state): State<AppState>) -> impl IntoResponse {
+    match state.client.presences().await {
+        Ok(data) => {
+            let presences: Vec<_> = data.presences.iter()
+                .map(|p| {
+                    let mut value = json!(p);
+                    value["summary"] = json!(p.valorant.as_ref().map(|v| v.summary()));
+                    value
+                })
+                .collect();
+            (StatusCode::OK, Json(json!({ "presences": presences }))).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+async fn friends_handler(State(state): State<AppState>) -> impl IntoResponse {
+    match state.client.friends().await {
+        Ok(data) => (StatusCode::OK, Json(json!(data))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn friend_requests_handler(State(state): State<AppState>) -> impl IntoResponse {
+    match state.client.friend_requests().await {
+        Ok(data) => (StatusCode::OK, Json(json!(data))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn send_friend_request_handler(
+    State(state): State<AppState>,
+    Path((name, tag)): Path<(String, String)>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    match state.client.send_friend_request(&name, &tag).await {
+        Ok(()) => (StatusCode::OK, Json(json!({ "requested": format!("{}#{}", name, tag) }))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn remove_friend_handler(
+    State(state): State<AppState>,
+    Path(puuid): Path<String>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    match state.client.remove_friend(&puuid).await {
+        Ok(()) => (StatusCode::OK, Json(json!({ "removed": puuid }))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn conversations_handler(State(state): State<AppState>) -> impl IntoResponse {
+    match state.client.conversations().await {
+        Ok(data) => {
+            let conversations: Vec<_> = data.conversations.iter()
+                .map(|c| {
+                    let mut value = json!(c);
+                    value["channel"] = json!(c.channel());
+                    value
+                })
+                .collect();
+            (StatusCode::OK, Json(json!({ "conversations": conversations }))).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+async fn chat_history_handler(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let Some(cid) = params.get("cid") else {
+        return (StatusCode::BAD_REQUEST, Json(json!({"error": "Missing cid"}))).into_response();
+    };
+    match state.client.chat_history(cid).await {
+        Ok(messages) => (StatusCode::OK, Json(json!(messages.iter().map(message_json).collect::<Vec<_>>()))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct ChatBody {
+    cid: String,
+    message: String,
+}
+
+async fn chat_send_handler(
+    State(state): State<AppState>,
+    Json(body): Json<ChatBody>,
+) -> impl IntoResponse {
+    if !state.allow_actions { return actions_disabled(); }
+    match state.client.send_message(&body.cid, &body.message).await {
+        Ok(message) => (StatusCode::OK, Json(message_json(&message))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+async fn chat_events_handler(State(state): State<AppState>) -> Sse<impl tokio_stream::Stream<Item = Result<Event, Infallible>>> {
+    let stream = state.client.chat_messages()
+        .map(|message| Ok(Event::default().data(message_json(&message).to_string())));
+
+    Sse::new(stream).keep_alive(axum::response::sse::KeepAlive::default())
+}
+
+/// A chat message with its channel spelled out.
+fn message_json(message: &ChatMessage) -> serde_json::Value {
+    let mut value = json!(message);
+    value["channel"] = json!(message.channel());
+    value
+}
+
 async fn history_handler(
     State(state): State<AppState>,
     Query(params): Query<HashMap<String, String>>,
@@ -137,9 +469,19 @@ async fn history_handler(
         .and_then(|v| v.parse().ok())
         .unwrap_or(20)
         .min(100);
-    let puuid = state.client.puuid().await;
-    match state.client.match_history(&puuid, count).await {
-        Ok(data) => (StatusCode::OK, Json(json!(data))).into_response(),
+    let start: u32 = params.get("start")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    let mut query = MatchHistoryQuery::latest(count).range(start, start.saturating_add(count));
+    if let Some(queue) = params.get("queue").filter(|q| !q.is_empty()) {
+        query = query.queue(Queue::from(queue.clone()));
+    }
+    let puuid = match params.get("puuid").map(String::as_str) {
+        Some(puuid) if puuid != "me" => puuid.to_string(),
+        _ => state.client.puuid().await,
+    };
+    match state.client.match_history_range(&puuid, &query).await {
+        Ok(history) => (StatusCode::OK, Json(json!(history))).into_response(),
         Err(e) => error_response(e),
     }
 }
@@ -157,6 +499,24 @@ async fn mmr_handler(
     }
 }
 
+async fn profile_handler(
+    State(state): State<AppState>,
+    Path(mut target_puuid): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+    if target_puuid == "me" {
+        target_puuid = state.client.puuid().await;
+    }
+    let count: u32 = params.get("count")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(10)
+        .min(50);
+    match PlayerProfile::build(&state.client, &target_puuid, count).await {
+        Ok(profile) => (StatusCode::OK, Json(json!(profile))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
 async fn match_detail_handler(
     State(state): State<AppState>,
     Path(match_id): Path<String>,
@@ -167,6 +527,16 @@ async fn match_detail_handler(
     }
 }
 
+async fn match_stats_handler(
+    State(state): State<AppState>,
+    Path(match_id): Path<String>,
+) -> impl IntoResponse {
+    match state.client.match_details(&match_id).await {
+        Ok(details) => (StatusCode::OK, Json(json!(derived_stats(&details)))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
 async fn names_handler(
     State(state): State<AppState>,
     Json(puuids): Json<Vec<String>>,
@@ -205,3 +575,11 @@ async fn log_events_handler(State(state): State<AppState>) -> Sse<impl tokio_str
     
     Sse::new(stream).keep_alive(axum::response::sse::KeepAlive::default())
 }
+
+async fn phase_events_handler(State(state): State<AppState>) -> Sse<impl tokio_stream::Stream<Item = Result<Event, Infallible>>> {
+    let rx = state.phase_tx.subscribe();
+    let stream = BroadcastStream::new(rx)
+        .filter_map(|result| result.ok().map(|change| Ok(Event::default().data(json!(change).to_string()))));
+
+    Sse::new(stream).keep_alive(axum::response::sse::KeepAlive::default())
+}
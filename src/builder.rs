@@ -0,0 +1,181 @@
+use reqwest::Client;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+
+use crate::auth::{self, LockfileData};
+use crate::client::{fetch_auth, fetch_client_version, ValorantClient};
+use crate::error::ValorantError;
+use crate::lockfile_watcher::ConnectionState;
+use crate::resolver::{EndpointResolver, RiotEndpoints};
+
+/// Overrides the lockfile location (also honoured by `LockfileData::read`).
+pub const ENV_LOCKFILE: &str = "VAL_LOCKFILE";
+/// Overrides the `ShooterGame.log` location used by `LogWatcher`.
+pub const ENV_LOG_PATH: &str = "VAL_LOG_PATH";
+pub const ENV_SHARD: &str = "VAL_SHARD";
+pub const ENV_REGION: &str = "VAL_REGION";
+pub const ENV_CLIENT_VERSION: &str = "VAL_CLIENT_VERSION";
+
+const FALLBACK_CLIENT_VERSION: &str = "release-10.03.0";
+
+/// Values that take precedence over what is derived from the token or local API.
+/// Kept on the client so they survive token refreshes and reconnects.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Overrides {
+    pub shard: Option<String>,
+    pub region: Option<String>,
+    pub client_version: Option<String>,
+    /// `ShooterGame.log`, for region detection and `ValorantClient::log_watcher`.
+    pub log_path: Option<PathBuf>,
+}
+
+/// Builder for [`ValorantClient`]. Explicit settings win over environment
+/// variables, which win over auto-detection.
+#[derive(Default)]
+pub struct ValorantClientBuilder {
+    lockfile_path: Option<PathBuf>,
+    lockfile: Option<LockfileData>,
+    overrides: Overrides,
+    http: Option<Client>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    endpoints: Option<Arc<dyn EndpointResolver>>,
+}
+
+impl ValorantClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the lockfile from this path instead of the default locations.
+    pub fn lockfile_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.lockfile_path = Some(path.into());
+        self
+    }
+
+    /// Use these credentials directly instead of reading a lockfile.
+    /// `watch_lockfile` is unavailable unless a path is also given.
+    pub fn lockfile(mut self, lockfile: LockfileData) -> Self {
+        self.lockfile = Some(lockfile);
+        self
+    }
+
+    /// PD shard, e.g. `"eu"`, `"na"`, `"ap"`, `"kr"`.
+    pub fn shard(mut self, shard: impl Into<String>) -> Self {
+        self.overrides.shard = Some(shard.into());
+        self
+    }
+
+    /// GLZ region, e.g. `"eu"`, `"na"`, `"latam"`, `"br"`.
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.overrides.region = Some(region.into());
+        self
+    }
+
+    /// Read `ShooterGame.log` from this path instead of the default location.
+    pub fn log_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.overrides.log_path = Some(path.into());
+        self
+    }
+
+    /// Value sent as `X-Riot-ClientVersion` instead of the detected one.
+    pub fn client_version(mut self, version: impl Into<String>) -> Self {
+        self.overrides.client_version = Some(version.into());
+        self
+    }
+
+    /// Use a preconfigured HTTP client. It must accept Riot's self-signed
+    /// localhost certificate; `timeout`/`connect_timeout` are ignored.
+    pub fn http_client(mut self, http: Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Total timeout for each request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Resolve PD/GLZ/shared/local base URLs with a custom resolver,
+    /// e.g. `StaticEndpoints` pointing at a mock server.
+    pub fn endpoints(mut self, endpoints: impl EndpointResolver + 'static) -> Self {
+        self.endpoints = Some(Arc::new(endpoints));
+        self
+    }
+
+    /// Resolve the configuration and connect to the running client.
+    pub async fn build(self) -> Result<ValorantClient, ValorantError> {
+        let http = match self.http {
+            Some(http) => http,
+            None => {
+                let mut builder = Client::builder().danger_accept_invalid_certs(true);
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                builder.build()?
+            }
+        };
+
+        let overrides = Overrides {
+            shard: self.overrides.shard.or_else(|| env_var(ENV_SHARD)),
+            region: self.overrides.region.or_else(|| env_var(ENV_REGION)),
+            client_version: self.overrides.client_version.or_else(|| env_var(ENV_CLIENT_VERSION)),
+            log_path: self.overrides.log_path.or_else(|| env_var(ENV_LOG_PATH).map(PathBuf::from)),
+        };
+        if let Some(version) = overrides.client_version.as_deref().filter(|v| !is_header_value(v)) {
+            return Err(ValorantError::InvalidClientVersion(version.to_string()));
+        }
+
+        let (lockfile, lockfile_path) = match (self.lockfile, self.lockfile_path) {
+            (Some(lockfile), path) => (lockfile, path),
+            (None, Some(path)) => (LockfileData::read_from(&path)?, Some(path)),
+            (None, None) => {
+                let path = auth::lockfile_path()?;
+                (LockfileData::read_from(&path)?, Some(path))
+            }
+        };
+
+        let endpoints = self.endpoints.unwrap_or_else(|| Arc::new(RiotEndpoints));
+        let auth = fetch_auth(&http, &*endpoints, &lockfile, &overrides).await?;
+        let client_version = match &overrides.client_version {
+            Some(version) => version.clone(),
+            None => fetch_client_version(&http, &*endpoints, &lockfile).await.ok()
+                .filter(|v| is_header_value(v))
+                .unwrap_or_else(|| FALLBACK_CLIENT_VERSION.to_string()),
+        };
+
+        let (connection_tx, _) = broadcast::channel(16);
+        Ok(ValorantClient {
+            http,
+            lockfile_path,
+            lockfile: Arc::new(RwLock::new(lockfile)),
+            auth: Arc::new(RwLock::new(auth)),
+            client_version: Arc::new(RwLock::new(client_version)),
+            overrides,
+            endpoints,
+            connection: Arc::new(RwLock::new(ConnectionState::Connected)),
+            connection_tx,
+            match_cache: Default::default(),
+        })
+    }
+}
+
+pub(crate) fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+/// `X-Riot-ClientVersion` must be a valid header value.
+pub(crate) fn is_header_value(value: &str) -> bool {
+    reqwest::header::HeaderValue::from_str(value).is_ok()
+}
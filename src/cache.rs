@@ -0,0 +1,31 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::models::match_data::MatchDetails;
+
+/// Completed matches never change, so their details are kept per client.
+const MATCH_CACHE_CAPACITY: usize = 256;
+
+/// Bounded match-details cache; the oldest entry is evicted first.
+#[derive(Debug, Default)]
+pub(crate) struct MatchCache {
+    entries: HashMap<String, MatchDetails>,
+    order: VecDeque<String>,
+}
+
+impl MatchCache {
+    pub(crate) fn get(&self, match_id: &str) -> Option<MatchDetails> {
+        self.entries.get(match_id).cloned()
+    }
+
+    pub(crate) fn insert(&mut self, match_id: String, details: MatchDetails) {
+        if self.entries.insert(match_id.clone(), details).is_some() {
+            return;
+        }
+        self.order.push_back(match_id);
+        while self.order.len() > MATCH_CACHE_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+}
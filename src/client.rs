@@ -1,50 +1,62 @@
 use reqwest::Client;
-use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, RwLock};
 
-use crate::auth::{LockfileData, RiotAuth};
+use crate::auth::{resolve_region, LockfileData, RiotAuth, TOKEN_REFRESH_MARGIN};
+use crate::builder::{Overrides, ValorantClientBuilder};
+use crate::cache::MatchCache;
 use crate::error::ValorantError;
+use crate::lockfile_watcher::ConnectionState;
+use crate::resolver::EndpointResolver;
 
 #[derive(Clone)]
 pub struct ValorantClient {
     pub(crate) http: Client,
-    pub(crate) lockfile: LockfileData,
+    pub(crate) lockfile_path: Option<PathBuf>,
+    pub(crate) lockfile: Arc<RwLock<LockfileData>>,
     pub(crate) auth: Arc<RwLock<RiotAuth>>,
-    pub(crate) client_version: String,
+    pub(crate) client_version: Arc<RwLock<String>>,
+    pub(crate) overrides: Overrides,
+    pub(crate) endpoints: Arc<dyn EndpointResolver>,
+    pub(crate) connection: Arc<RwLock<ConnectionState>>,
+    pub(crate) connection_tx: broadcast::Sender<ConnectionState>,
+    pub(crate) match_cache: Arc<Mutex<MatchCache>>,
 }
 
 impl ValorantClient {
     /// Connect to running Valorant instance
     pub async fn connect() -> Result<Self, ValorantError> {
-        let http = Client::builder()
-            .danger_accept_invalid_certs(true)
-            .build()?;
+        Self::builder().build().await
+    }
 
-        let lockfile = LockfileData::read()?;
-        let auth = RiotAuth::fetch(&http, &lockfile).await?;
-        let client_version = fetch_client_version(&http, &lockfile).await
-            .unwrap_or_else(|_| "release-10.03.0".to_string());
+    /// Configure lockfile location, region overrides, HTTP client and timeouts.
+    pub fn builder() -> ValorantClientBuilder {
+        ValorantClientBuilder::new()
+    }
 
-        Ok(Self {
-            http,
-            lockfile,
-            auth: Arc::new(RwLock::new(auth)),
-            client_version,
-        })
+    pub(crate) async fn local_url(&self) -> String {
+        self.endpoints.local(&*self.lockfile.read().await)
     }
 
-    pub(crate) fn local_url(&self) -> String {
-        format!("{}://127.0.0.1:{}", self.lockfile.protocol, self.lockfile.port)
+    /// Request against the local Riot Client API, authenticated with the lockfile password.
+    pub(crate) async fn local_request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}{}", self.local_url().await, path);
+        let password = self.lockfile.read().await.password.clone();
+        self.http.request(method, url).basic_auth("riot", Some(password))
     }
 
     pub(crate) async fn pd_url(&self) -> String {
-        let auth = self.auth.read().await;
-        format!("https://pd.{}.a.pvp.net", auth.shard)
+        self.endpoints.pd(&self.auth.read().await.shard)
+    }
+
+    pub(crate) async fn shared_url(&self) -> String {
+        self.endpoints.shared(&self.auth.read().await.shard)
     }
 
     pub(crate) async fn glz_url(&self) -> String {
         let auth = self.auth.read().await;
-        format!("https://glz-{}-1.{}.a.pvp.net", auth.region, auth.shard)
+        self.endpoints.glz(&auth.region, &auth.shard)
     }
 
     pub(crate) async fn auth_headers(&self) -> reqwest::header::HeaderMap {
@@ -55,7 +67,7 @@ impl ValorantClient {
         headers.insert("X-Riot-Entitlements-JWT",
             auth.entitlements_token.parse().unwrap());
         headers.insert("X-Riot-ClientVersion",
-            self.client_version.parse().unwrap()); 
+            self.client_version.read().await.parse().unwrap());
         headers.insert("X-Riot-ClientPlatform",
             "ew0KCSJwbGF0Zm9ybVR5cGUiOiAiUEMiLA0KCSJwbGF0Zm9ybU9TIjogIldpbmRvd3MiLA0KCSJwbGF0Zm9ybU9TVmVyc2lvbiI6ICIxMC4wLjE5MDQyLjEuMjU2LjY0Yml0IiwNCgkicGxhdGZvcm1DaGlwc2V0IjogIlVua25vd24iDQp9".parse().unwrap());
         headers
@@ -69,11 +81,56 @@ impl ValorantClient {
         self.auth.read().await.clone()
     }
 
+    /// Fetch a fresh access/entitlements token pair from the local client.
+    /// Region and shard are kept; they are only resolved on (re)connect.
+    pub async fn refresh_auth(&self) -> Result<(), ValorantError> {
+        let fresh = self.fetch_tokens().await?;
+        let mut auth = self.auth.write().await;
+        *auth = RiotAuth { region: auth.region.clone(), shard: auth.shard.clone(), ..fresh };
+        Ok(())
+    }
+
+    /// Refresh the token ahead of time if it is about to expire.
+    pub(crate) async fn ensure_fresh_auth(&self) -> Result<(), ValorantError> {
+        if !self.auth.read().await.expires_within(TOKEN_REFRESH_MARGIN) {
+            return Ok(());
+        }
+        let mut auth = self.auth.write().await;
+        // Another task may have refreshed while we waited for the lock.
+        if auth.expires_within(TOKEN_REFRESH_MARGIN) {
+            let fresh = self.fetch_tokens().await?;
+            *auth = RiotAuth { region: auth.region.clone(), shard: auth.shard.clone(), ..fresh };
+        }
+        Ok(())
+    }
+
+    async fn fetch_tokens(&self) -> Result<RiotAuth, ValorantError> {
+        let lockfile = self.lockfile.read().await.clone();
+        RiotAuth::fetch_tokens(&self.http, &self.endpoints.local(&lockfile), &lockfile).await
+    }
+
+    /// Send an authenticated request to the PD/GLZ clusters.
+    /// `build` is called with fresh auth headers; on 401 the token is
+    /// refreshed and the request is retried once. A 403 is a real permission
+    /// error (e.g. locking an agent outside agent select) and is not retried.
+    pub(crate) async fn send_authed<F>(&self, build: F) -> Result<reqwest::Response, ValorantError>
+    where
+        F: Fn(reqwest::header::HeaderMap) -> reqwest::RequestBuilder,
+    {
+        self.ensure_fresh_auth().await?;
+        let resp = build(self.auth_headers().await).send().await?;
+        if resp.status() != 401 {
+            return Ok(resp);
+        }
+        self.refresh_auth().await?;
+        Ok(build(self.auth_headers().await).send().await?)
+    }
+
     /// Generic GET against the PD (Player Data) cluster.
     /// Use this for endpoints the library doesn't yet wrap natively.
     pub async fn raw_get_pd(&self, path: &str) -> Result<serde_json::Value, ValorantError> {
         let url = format!("{}{}", self.pd_url().await, path);
-        let resp = self.http.get(&url).headers(self.auth_headers().await).send().await?;
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
         if !resp.status().is_success() {
             return Err(ValorantError::ApiError {
                 status: resp.status().as_u16(),
@@ -86,7 +143,7 @@ impl ValorantClient {
     /// Generic GET against the GLZ (Game Lobby Zone) cluster.
     pub async fn raw_get_glz(&self, path: &str) -> Result<serde_json::Value, ValorantError> {
         let url = format!("{}{}", self.glz_url().await, path);
-        let resp = self.http.get(&url).headers(self.auth_headers().await).send().await?;
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
         if !resp.status().is_success() {
             return Err(ValorantError::ApiError {
                 status: resp.status().as_u16(),
@@ -103,11 +160,7 @@ impl ValorantClient {
         body: &B,
     ) -> Result<serde_json::Value, ValorantError> {
         let url = format!("{}{}", self.pd_url().await, path);
-        let resp = self.http
-            .put(&url)
-            .headers(self.auth_headers().await)
-            .json(body)
-            .send().await?;
+        let resp = self.send_authed(|h| self.http.put(&url).headers(h).json(body)).await?;
         if !resp.status().is_success() {
             return Err(ValorantError::ApiError {
                 status: resp.status().as_u16(),
@@ -118,8 +171,50 @@ impl ValorantClient {
     }
 }
 
-async fn fetch_client_version(http: &Client, lockfile: &LockfileData) -> Result<String, ValorantError> {
-    let base = format!("{}://127.0.0.1:{}", lockfile.protocol, lockfile.port);
+/// Pass 2xx responses through; turn anything else into `ApiError`
+/// carrying Riot's error message when there is one.
+pub(crate) async fn check_status(resp: reqwest::Response) -> Result<reqwest::Response, ValorantError> {
+    if resp.status().is_success() {
+        return Ok(resp);
+    }
+    let status = resp.status().as_u16();
+    let body = resp.text().await.unwrap_or_default();
+    let message = serde_json::from_str::<serde_json::Value>(&body).ok()
+        .and_then(|v| v["message"].as_str().map(str::to_string))
+        .unwrap_or(body);
+    Err(ValorantError::ApiError { status, message })
+}
+
+/// Fetch tokens for `lockfile`; region and shard come from the overrides,
+/// falling back to detection for whichever is missing.
+pub(crate) async fn fetch_auth(
+    http: &Client,
+    endpoints: &dyn EndpointResolver,
+    lockfile: &LockfileData,
+    overrides: &Overrides,
+) -> Result<RiotAuth, ValorantError> {
+    let base = endpoints.local(lockfile);
+    let mut auth = RiotAuth::fetch_tokens(http, &base, lockfile).await?;
+    (auth.region, auth.shard) = match (&overrides.region, &overrides.shard) {
+        (Some(region), Some(shard)) => (region.clone(), shard.clone()),
+        (region, shard) => {
+            let (detected_region, detected_shard) =
+                resolve_region(http, &base, lockfile, overrides.log_path.as_deref()).await?;
+            (
+                region.clone().unwrap_or(detected_region),
+                shard.clone().unwrap_or(detected_shard),
+            )
+        }
+    };
+    Ok(auth)
+}
+
+pub(crate) async fn fetch_client_version(
+    http: &Client,
+    endpoints: &dyn EndpointResolver,
+    lockfile: &LockfileData,
+) -> Result<String, ValorantError> {
+    let base = endpoints.local(lockfile);
     let resp: serde_json::Value = http
         .get(format!("{}/product-session/v1/external-sessions", base))
         .basic_auth("riot", Some(&lockfile.password))
@@ -0,0 +1,98 @@
+use futures_util::StreamExt;
+use serde_json::json;
+use tokio_stream::Stream;
+
+use crate::client::{check_status, ValorantClient};
+use crate::error::ValorantError;
+use crate::models::chat::{ChatChannel, ChatMessage, ChatMessages, Conversations};
+use crate::models::friends::{FriendRequests, Friends};
+use crate::models::presence::{Presence, Presences};
+use crate::websocket::{events, LocalEventType};
+
+impl ValorantClient {
+    /// Presence of the user and every online friend, with Valorant's private
+    /// payload decoded.
+    pub async fn presences(&self) -> Result<Presences, ValorantError> {
+        let resp = self.local_request(reqwest::Method::GET, "/chat/v4/presences").await
+            .send().await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    pub async fn friends(&self) -> Result<Friends, ValorantError> {
+        let resp = self.local_request(reqwest::Method::GET, "/chat/v4/friends").await
+            .send().await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Pending friend requests, both sent and received.
+    pub async fn friend_requests(&self) -> Result<FriendRequests, ValorantError> {
+        let resp = self.local_request(reqwest::Method::GET, "/chat/v4/friendrequests").await
+            .send().await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    pub async fn send_friend_request(&self, name: &str, tag: &str) -> Result<(), ValorantError> {
+        let resp = self.local_request(reqwest::Method::POST, "/chat/v4/friendrequests").await
+            .json(&json!({ "game_name": name, "game_tag": tag }))
+            .send().await?;
+        check_status(resp).await?;
+        Ok(())
+    }
+
+    pub async fn remove_friend(&self, puuid: &str) -> Result<(), ValorantError> {
+        let resp = self.local_request(reqwest::Method::DELETE, "/chat/v4/friends").await
+            .json(&json!({ "puuid": puuid }))
+            .send().await?;
+        check_status(resp).await?;
+        Ok(())
+    }
+
+    /// Presence changes pushed over the local WebSocket, one per player.
+    pub fn presence_updates(&self) -> impl Stream<Item = Presence> {
+        self.subscribe_typed::<Presences>(events::PRESENCES)
+            .flat_map(|(_, update)| futures_util::stream::iter(update.presences))
+    }
+
+    /// Open conversations: party, pregame, team and all chat, and whispers.
+    pub async fn conversations(&self) -> Result<Conversations, ValorantError> {
+        let resp = self.local_request(reqwest::Method::GET, "/chat/v6/conversations").await
+            .send().await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Messages in a conversation, oldest first.
+    pub async fn chat_history(&self, cid: &str) -> Result<Vec<ChatMessage>, ValorantError> {
+        let path = format!("/chat/v6/messages?cid={}", urlencoding::encode(cid));
+        let resp = self.local_request(reqwest::Method::GET, &path).await
+            .send().await?;
+        let mut history: ChatMessages = check_status(resp).await?.json().await?;
+        history.messages.retain(|m| m.cid == cid);
+        history.messages.sort_by_key(|m| m.time);
+        Ok(history.messages)
+    }
+
+    /// Send `text` to a conversation and return the message as delivered.
+    pub async fn send_message(&self, cid: &str, text: &str) -> Result<ChatMessage, ValorantError> {
+        let body = json!({
+            "cid": cid,
+            "message": text,
+            "type": ChatChannel::from_cid(cid).message_type(),
+        });
+        let resp = self.local_request(reqwest::Method::POST, "/chat/v6/messages").await
+            .json(&body)
+            .send().await?;
+        let sent: ChatMessages = check_status(resp).await?.json().await?;
+        sent.messages.into_iter().next().ok_or_else(|| ValorantError::ApiError {
+            status: 502,
+            message: "Chat did not echo the sent message".into(),
+        })
+    }
+
+    /// Messages as they arrive in any conversation, including the user's own.
+    /// Updates to existing messages (e.g. read state) are not repeated.
+    pub fn chat_messages(&self) -> impl Stream<Item = ChatMessage> {
+        self.subscribe_typed::<ChatMessages>(events::CHAT_MESSAGES)
+            .filter(|(event, _)| std::future::ready(event.event_type == LocalEventType::Create))
+            .flat_map(|(_, update)| futures_util::stream::iter(update.messages))
+    }
+}
@@ -0,0 +1,12 @@
+use crate::client::{check_status, ValorantClient};
+use crate::error::ValorantError;
+use crate::models::content::Content;
+
+impl ValorantClient {
+    /// Episodes and acts, with the currently active ones flagged.
+    pub async fn content(&self) -> Result<Content, ValorantError> {
+        let url = format!("{}/content-service/v3/content", self.shared_url().await);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+}
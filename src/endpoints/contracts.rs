@@ -0,0 +1,19 @@
+use crate::client::{check_status, ValorantClient};
+use crate::error::ValorantError;
+use crate::models::contracts::{ContractDefinitions, Contracts};
+
+impl ValorantClient {
+    /// Battlepass and agent contract levels plus mission progress.
+    pub async fn contracts(&self, puuid: &str) -> Result<Contracts, ValorantError> {
+        let url = format!("{}/contracts/v1/contracts/{}", self.pd_url().await, puuid);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Names and per-level XP costs of every contract.
+    pub async fn contract_definitions(&self) -> Result<ContractDefinitions, ValorantError> {
+        let url = format!("{}/contract-definitions/v2/definitions", self.pd_url().await);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::client::{check_status, ValorantClient};
+use crate::error::ValorantError;
+use crate::models::loadout::PlayerLoadout;
+
+impl ValorantClient {
+    /// Equipped skins, buddies, sprays, card and title.
+    pub async fn player_loadout(&self, puuid: &str) -> Result<PlayerLoadout, ValorantError> {
+        let url = format!("{}/personalization/v2/players/{}/playerloadout", self.pd_url().await, puuid);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Replace the equipped loadout. Start from `player_loadout` and edit it;
+    /// items the player doesn't own are rejected by Riot.
+    pub async fn set_player_loadout(&self, puuid: &str, loadout: &PlayerLoadout) -> Result<PlayerLoadout, ValorantError> {
+        let url = format!("{}/personalization/v2/players/{}/playerloadout", self.pd_url().await, puuid);
+        let resp = self.send_authed(|h| self.http.put(&url).headers(h).json(loadout)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+}
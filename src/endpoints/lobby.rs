@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::client::ValorantClient;
+use crate::error::ValorantError;
+use crate::models::lobby::{LiveLobby, LobbyPhase, LobbyPlayer};
+use crate::models::player::{CompetitiveTier, MmrData, PlayerIdentity};
+use crate::models::pregame::CharacterSelectionState;
+use crate::premade::Premades;
+
+/// MMR lookups in flight at once; a full lobby is ten requests.
+const MMR_CONCURRENCY: usize = 5;
+
+/// What the match endpoints tell us about a player before enrichment.
+struct Seat {
+    puuid: String,
+    team_id: String,
+    character_id: String,
+    party_id: String,
+    selection_state: Option<CharacterSelectionState>,
+    identity: PlayerIdentity,
+}
+
+impl ValorantClient {
+    /// Everyone in the current pregame or coregame match, with names, ranks,
+    /// skins and party. Returns `NotInMatch` outside of a match.
+    pub async fn live_lobby(&self) -> Result<LiveLobby, ValorantError> {
+        let puuid = self.puuid().await;
+
+        let (phase, match_id, map_id, queue_id, seats) = match self.coregame_player(&puuid).await {
+            Ok(player) => {
+                let game = self.coregame_match(&player.match_id).await?;
+                let queue_id = game.matchmaking.map(|m| m.queue_id).unwrap_or_default();
+                let seats = game.players.into_iter()
+                    .filter(|p| !p.is_coach)
+                    .map(|p| Seat {
+                        puuid: p.puuid,
+                        team_id: p.team_id,
+                        character_id: p.character_id,
+                        party_id: p.party_id,
+                        selection_state: None,
+                        identity: p.identity,
+                    })
+                    .collect::<Vec<_>>();
+                (LobbyPhase::InGame, game.match_id, game.map_id, queue_id, seats)
+            }
+            Err(ValorantError::NotInMatch) => {
+                let player = self.pregame_player(&puuid).await?;
+                let pregame = self.pregame_match(&player.match_id).await?;
+                let seats = pregame.ally_team.iter()
+                    .chain(pregame.enemy_team.iter())
+                    .flat_map(|team| team.players.iter().map(move |p| Seat {
+                        puuid: p.puuid.clone(),
+                        team_id: team.team_id.clone(),
+                        character_id: p.character_id.clone(),
+                        party_id: p.party_id.clone(),
+                        selection_state: Some(p.selection_state),
+                        identity: p.identity.clone(),
+                    }))
+                    .collect();
+                (LobbyPhase::Pregame, pregame.id, pregame.map_id, pregame.queue_id, seats)
+            }
+            Err(e) => return Err(e),
+        };
+        let puuids: Vec<String> = seats.iter().map(|s| s.puuid.clone()).collect();
+
+        // Names are required; everything else degrades to "unknown".
+        let (names, content, loadouts, mmr) = tokio::join!(
+            self.resolve_names(&puuids),
+            self.content(),
+            async {
+                match phase {
+                    LobbyPhase::InGame => self.coregame_loadouts(&match_id).await.ok(),
+                    LobbyPhase::Pregame => None,
+                }
+            },
+            self.mmr_for(&puuids),
+        );
+        let names: HashMap<String, (String, String)> = names?.into_iter()
+            .map(|n| (n.puuid, (n.name, n.tag)))
+            .collect();
+        // Without content there is no act to read ranks for; don't report everyone as unranked.
+        let content = content?;
+        let current_act = content.current_act().map(|s| s.id.clone());
+        let previous_act = content.previous_act().map(|s| s.id.clone());
+
+        let premades = Premades::from_players(seats.iter()
+            .map(|s| (s.puuid.as_str(), s.team_id.as_str(), s.party_id.as_str())));
+        let me = seats.iter().find(|s| s.puuid == puuid);
+        let my_team = me.map(|s| s.team_id.clone());
+        let my_party = me.map(|s| s.party_id.clone()).filter(|id| !id.is_empty());
+        let players = seats.into_iter()
+            .map(|seat| {
+                let party_id = Some(seat.party_id).filter(|id| !id.is_empty());
+                let is_self = seat.puuid == puuid;
+                let in_my_party = my_party.is_some() && party_id == my_party;
+                let hidden = seat.identity.incognito && !is_self && !in_my_party;
+                let (name, tag) = match names.get(&seat.puuid) {
+                    Some((name, tag)) if !hidden => (Some(name.clone()), Some(tag.clone())),
+                    _ => (None, None),
+                };
+                let mmr = mmr.get(&seat.puuid);
+                let skins = loadouts.as_ref()
+                    .and_then(|l| l.for_player(&seat.puuid))
+                    .map(|l| l.loadout.guns())
+                    .unwrap_or_default();
+                LobbyPlayer {
+                    name,
+                    tag,
+                    incognito: seat.identity.incognito,
+                    is_ally: my_team.as_ref() == Some(&seat.team_id),
+                    is_self,
+                    team_id: seat.team_id,
+                    character_id: seat.character_id,
+                    selection_state: seat.selection_state,
+                    account_level: (!seat.identity.hide_account_level).then_some(seat.identity.account_level),
+                    current_tier: mmr.zip(current_act.as_deref())
+                        .map(|(m, act)| m.season_tier(act))
+                        .unwrap_or(CompetitiveTier::Unranked),
+                    ranked_rating: mmr.zip(current_act.as_deref())
+                        .map(|(m, act)| m.ranked_rating(act))
+                        .unwrap_or(0),
+                    leaderboard_rank: mmr.zip(current_act.as_deref()).and_then(|(m, act)| m.leaderboard_rank(act)),
+                    peak_tier: mmr.map(|m| m.peak_tier(&content)).unwrap_or(CompetitiveTier::Unranked),
+                    previous_act_tier: mmr.zip(previous_act.as_deref())
+                        .map(|(m, act)| m.season_tier(act))
+                        .unwrap_or(CompetitiveTier::Unranked),
+                    player_card_id: seat.identity.player_card_id,
+                    player_title_id: seat.identity.player_title_id,
+                    skins,
+                    party_id,
+                    premade_group: premades.index_of(&seat.puuid),
+                    puuid: seat.puuid,
+                }
+            })
+            .collect();
+
+        Ok(LiveLobby { phase, match_id, map_id, queue_id, players, premades })
+    }
+
+    /// MMR for each player, fetched concurrently. Failed lookups are left out.
+    async fn mmr_for(&self, puuids: &[String]) -> HashMap<String, MmrData> {
+        let permits = Arc::new(Semaphore::new(MMR_CONCURRENCY));
+        let mut tasks = JoinSet::new();
+        for puuid in puuids {
+            let client = self.clone();
+            let permits = permits.clone();
+            let puuid = puuid.clone();
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                let mmr = client.mmr(&puuid).await;
+                (puuid, mmr)
+            });
+        }
+        let mut results = HashMap::new();
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok((puuid, Ok(mmr))) = joined {
+                results.insert(puuid, mmr);
+            }
+        }
+        results
+    }
+}
@@ -5,9 +5,8 @@ use crate::models::session::SessionData;
 impl ValorantClient {
     /// Get current game session state
     pub async fn session(&self) -> Result<SessionData, ValorantError> {
-        let resp: serde_json::Value = self.http
-            .get(format!("{}/product-session/v1/external-sessions", self.local_url()))
-            .basic_auth("riot", Some(&self.lockfile.password))
+        let resp: serde_json::Value = self
+            .local_request(reqwest::Method::GET, "/product-session/v1/external-sessions").await
             .send().await?.json().await?;
         
         Ok(serde_json::from_value(resp)?)
@@ -15,9 +14,8 @@ impl ValorantClient {
 
     /// Get client version (needed for headers)
     pub async fn client_version(&self) -> Result<String, ValorantError> {
-        let resp: serde_json::Value = self.http
-            .get(format!("{}/product-session/v1/external-sessions", self.local_url()))
-            .basic_auth("riot", Some(&self.lockfile.password))
+        let resp: serde_json::Value = self
+            .local_request(reqwest::Method::GET, "/product-session/v1/external-sessions").await
             .send().await?.json().await?;
         
         resp.get("clientVersion")
@@ -1,4 +1,11 @@
+pub mod chat;
+pub mod content;
+pub mod contracts;
 pub mod coregame;
+pub mod loadout;
+pub mod lobby;
 pub mod local;
+pub mod party;
 pub mod pd;
 pub mod pregame;
+pub mod store;
@@ -0,0 +1,60 @@
+use serde_json::json;
+
+use crate::client::{check_status, ValorantClient};
+use crate::error::ValorantError;
+use crate::models::party::{Accessibility, Party, PartyPlayer};
+
+impl ValorantClient {
+    /// Which party a player is in.
+    pub async fn party_player(&self, puuid: &str) -> Result<PartyPlayer, ValorantError> {
+        let url = format!("{}/parties/v1/players/{}", self.glz_url().await, puuid);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    pub async fn party(&self, party_id: &str) -> Result<Party, ValorantError> {
+        let url = format!("{}/parties/v1/parties/{}", self.glz_url().await, party_id);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Open the party to friends, or close it to invites only.
+    pub async fn party_set_accessibility(&self, party_id: &str, accessibility: Accessibility) -> Result<Party, ValorantError> {
+        self.party_post(party_id, "accessibility", Some(json!({ "accessibility": accessibility }))).await
+    }
+
+    /// Switch the queue, e.g. `"competitive"`, `"unrated"`, `"swiftplay"`.
+    pub async fn party_change_queue(&self, party_id: &str, queue_id: &str) -> Result<Party, ValorantError> {
+        self.party_post(party_id, "queue", Some(json!({ "queueId": queue_id }))).await
+    }
+
+    pub async fn party_invite_by_name(&self, party_id: &str, name: &str, tag: &str) -> Result<Party, ValorantError> {
+        let path = format!("invites/name/{}/tag/{}", urlencoding::encode(name), urlencoding::encode(tag));
+        self.party_post(party_id, &path, None).await
+    }
+
+    pub async fn party_join_matchmaking(&self, party_id: &str) -> Result<Party, ValorantError> {
+        self.party_post(party_id, "matchmaking/join", None).await
+    }
+
+    pub async fn party_leave_matchmaking(&self, party_id: &str) -> Result<Party, ValorantError> {
+        self.party_post(party_id, "matchmaking/leave", None).await
+    }
+
+    pub async fn party_set_ready(&self, party_id: &str, puuid: &str, ready: bool) -> Result<Party, ValorantError> {
+        let path = format!("members/{}/setReady", puuid);
+        self.party_post(party_id, &path, Some(json!({ "ready": ready }))).await
+    }
+
+    async fn party_post(&self, party_id: &str, action: &str, body: Option<serde_json::Value>) -> Result<Party, ValorantError> {
+        let url = format!("{}/parties/v1/parties/{}/{}", self.glz_url().await, party_id, action);
+        let resp = self.send_authed(|h| {
+            let req = self.http.post(&url).headers(h);
+            match &body {
+                Some(body) => req.json(body),
+                None => req,
+            }
+        }).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+}
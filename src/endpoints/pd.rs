@@ -1,27 +1,40 @@
-use crate::client::ValorantClient;
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream};
+
+use crate::client::{check_status, ValorantClient};
 use crate::error::ValorantError;
-use crate::models::match_data::{MatchDetails, MatchHistoryEntry};
-use crate::models::player::{NameEntry, MmrData};
+use crate::models::account_xp::AccountXp;
+use crate::models::match_data::{MatchDetails, MatchHistoryEntry, MatchHistoryPage, MatchHistoryQuery, Queue};
+use crate::models::player::{CompetitiveUpdates, NameEntry, MmrData};
 
 impl ValorantClient {
-    /// Resolve name+tag → PUUID.
-    /// NOTE: The local Riot API has no name→PUUID endpoint.
-    /// This method exists as a placeholder — callers should use Henrik API for this lookup.
-    pub async fn lookup_player(&self, _name: &str, _tag: &str) -> Result<String, ValorantError> {
+    /// Resolve name+tag → PUUID for the user and anyone on their friends list.
+    /// Riot has no public name→PUUID endpoint; for strangers use an external
+    /// API such as Henrik's.
+    pub async fn lookup_player(&self, name: &str, tag: &str) -> Result<String, ValorantError> {
+        // The chat service may be down; the user can still look themselves up.
+        if let Ok(friends) = self.friends().await {
+            if let Some(friend) = friends.find_by_riot_id(name, tag) {
+                return Ok(friend.puuid.clone());
+            }
+        }
+        let puuid = self.puuid().await;
+        let own = self.resolve_names(std::slice::from_ref(&puuid)).await?;
+        if own.iter().any(|n| n.name.eq_ignore_ascii_case(name) && n.tag.eq_ignore_ascii_case(tag)) {
+            return Ok(puuid);
+        }
         Err(ValorantError::ApiError {
-            status: 501,
-            message: "Name→PUUID lookup requires an external API (use Henrik)".into(),
+            status: 404,
+            message: format!("{}#{} is not on the friends list", name, tag),
         })
     }
 
     /// PUUID → name + tag for multiple players
     pub async fn resolve_names(&self, puuids: &[String]) -> Result<Vec<NameEntry>, ValorantError> {
         let url = format!("{}/name-service/v2/players", self.pd_url().await);
-        let resp: Vec<serde_json::Value> = self.http
-            .put(&url)
-            .headers(self.auth_headers().await)
-            .json(puuids)
-            .send().await?.json().await?;
+        let resp: Vec<serde_json::Value> = self
+            .send_authed(|h| self.http.put(&url).headers(h).json(puuids))
+            .await?.json().await?;
             
         Ok(resp.iter().map(|v| NameEntry {
             puuid: v["Subject"].as_str().unwrap_or("").to_string(),
@@ -30,36 +43,110 @@ impl ValorantClient {
         }).collect())
     }
 
-    /// Get match history for a player
+    /// Get the newest `count` competitive matches for a player.
+    /// Use `match_history_range` for other queues or every queue.
     pub async fn match_history(&self, puuid: &str, count: u32) -> Result<Vec<MatchHistoryEntry>, ValorantError> {
-        let url = format!("{}/match-history/v1/history/{}?startIndex=0&endIndex={}&queue=competitive", 
-            self.pd_url().await, puuid, count);
-        let resp: serde_json::Value = self.http.get(&url)
-            .headers(self.auth_headers().await)
-            .send().await?.json().await?;
-            
-        if let Some(history) = resp.get("History").and_then(|h| h.as_array()) {
-            let entries: Result<Vec<MatchHistoryEntry>, _> = history.iter()
-                .map(|v| serde_json::from_value(v.clone()))
-                .collect();
-            Ok(entries?)
-        } else {
-            Ok(vec![])
+        let query = MatchHistoryQuery::latest(count).queue(Queue::Competitive);
+        self.match_history_range(puuid, &query).await
+    }
+
+    /// Get every match in `query`'s range, fetching as many pages as it spans.
+    pub async fn match_history_range(&self, puuid: &str, query: &MatchHistoryQuery) -> Result<Vec<MatchHistoryEntry>, ValorantError> {
+        let mut history = Vec::new();
+        let mut start = query.start;
+        while start < query.end {
+            let end = query.end.min(start.saturating_add(MatchHistoryQuery::MAX_PAGE_SIZE));
+            let page = self.match_history_page(puuid, &query.clone().range(start, end)).await?;
+            let fetched = page.history.len() as u32;
+            history.extend(page.history);
+            start = start.saturating_add(fetched);
+            if fetched == 0 || start >= page.total {
+                break;
+            }
         }
+        history.truncate(query.end.saturating_sub(query.start) as usize);
+        Ok(history)
+    }
+
+    /// Get one page of match history. Riot caps a page at
+    /// `MatchHistoryQuery::MAX_PAGE_SIZE` matches; see `match_history_range`.
+    pub async fn match_history_page(&self, puuid: &str, query: &MatchHistoryQuery) -> Result<MatchHistoryPage, ValorantError> {
+        let url = format!("{}/match-history/v1/history/{}?{}",
+            self.pd_url().await, puuid, query.to_query_string());
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Page through a player's entire match history, newest first.
+    /// Pages are fetched lazily as the stream is consumed; an error ends the stream.
+    pub fn match_history_stream(&self, puuid: &str, queue: Option<Queue>) -> impl Stream<Item = Result<MatchHistoryEntry, ValorantError>> {
+        let page_size = MatchHistoryQuery::MAX_PAGE_SIZE;
+        let (tx, rx) = mpsc::channel(page_size as usize);
+        let client = self.clone();
+        let puuid = puuid.to_string();
+        tokio::spawn(async move {
+            let mut start = 0;
+            loop {
+                let query = MatchHistoryQuery { start, end: start.saturating_add(page_size), queue: queue.clone() };
+                let page = match client.match_history_page(&puuid, &query).await {
+                    Ok(page) => page,
+                    Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                };
+                let fetched = page.history.len() as u32;
+                for entry in page.history {
+                    if tx.send(Ok(entry)).await.is_err() {
+                        return;
+                    }
+                }
+                start = start.saturating_add(fetched);
+                if fetched == 0 || start >= page.total {
+                    return;
+                }
+            }
+        });
+        ReceiverStream::new(rx)
     }
 
     /// Get full match details (post-match stats, HS%, damage, etc.)
+    /// Completed matches are cached on the client.
     pub async fn match_details(&self, match_id: &str) -> Result<MatchDetails, ValorantError> {
+        if let Some(details) = self.match_cache.lock().unwrap().get(match_id) {
+            return Ok(details);
+        }
         let url = format!("{}/match-details/v1/matches/{}", self.pd_url().await, match_id);
-        Ok(self.http.get(&url).headers(self.auth_headers().await)
-            .send().await?.json().await?)
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        let details: MatchDetails = check_status(resp).await?.json().await?;
+        if details.match_info.is_completed {
+            self.match_cache.lock().unwrap().insert(match_id.to_string(), details.clone());
+        }
+        Ok(details)
     }
 
     /// Get MMR / rank data for a player
     pub async fn mmr(&self, puuid: &str) -> Result<MmrData, ValorantError> {
         let url = format!("{}/mmr/v1/players/{}", self.pd_url().await, puuid);
-        Ok(self.http.get(&url).headers(self.auth_headers().await)
-            .send().await?.json().await?)
+        Ok(self.send_authed(|h| self.http.get(&url).headers(h)).await?.json().await?)
+    }
+
+    /// RR gained or lost per game, newest first. `queue` defaults to every queue.
+    pub async fn competitive_updates(&self, puuid: &str, start: u32, end: u32, queue: Option<Queue>) -> Result<CompetitiveUpdates, ValorantError> {
+        let mut url = format!("{}/mmr/v1/players/{}/competitiveupdates?startIndex={}&endIndex={}",
+            self.pd_url().await, puuid, start, end);
+        if let Some(queue) = queue {
+            url.push_str(&format!("&queue={}", queue.id()));
+        }
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Account level and XP earned per match
+    pub async fn account_xp(&self, puuid: &str) -> Result<AccountXp, ValorantError> {
+        let url = format!("{}/account-xp/v1/players/{}", self.pd_url().await, puuid);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
     }
 
     /// Get competitive leaderboard for a region
@@ -68,7 +155,6 @@ impl ValorantClient {
             self.pd_url().await, 
             self.auth.read().await.region,
             season_id, start, size);
-        Ok(self.http.get(&url).headers(self.auth_headers().await)
-            .send().await?.json().await?)
+        Ok(self.send_authed(|h| self.http.get(&url).headers(h)).await?.json().await?)
     }
 }
@@ -1,19 +1,40 @@
-use crate::client::ValorantClient;
+use crate::client::{check_status, ValorantClient};
 use crate::error::ValorantError;
 use crate::models::pregame::{PreGameMatch, PreGamePlayer};
 
 impl ValorantClient {
     pub async fn pregame_player(&self, puuid: &str) -> Result<PreGamePlayer, ValorantError> {
         let url = format!("{}/pregame/v1/players/{}", self.glz_url().await, puuid);
-        let resp = self.http.get(&url).headers(self.auth_headers().await)
-            .send().await?;
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
         if resp.status() == 404 { return Err(ValorantError::NotInMatch); }
         Ok(resp.json().await?)
     }
 
     pub async fn pregame_match(&self, match_id: &str) -> Result<PreGameMatch, ValorantError> {
         let url = format!("{}/pregame/v1/matches/{}", self.glz_url().await, match_id);
-        Ok(self.http.get(&url).headers(self.auth_headers().await)
-            .send().await?.json().await?)
+        Ok(self.send_authed(|h| self.http.get(&url).headers(h)).await?.json().await?)
+    }
+
+    /// Hover an agent in agent select. Returns the updated match.
+    pub async fn pregame_select_agent(&self, match_id: &str, agent_id: &str) -> Result<PreGameMatch, ValorantError> {
+        Ok(self.pregame_action(match_id, &format!("select/{}", agent_id)).await?.json().await?)
+    }
+
+    /// Lock in an agent. Cannot be undone.
+    pub async fn pregame_lock_agent(&self, match_id: &str, agent_id: &str) -> Result<PreGameMatch, ValorantError> {
+        Ok(self.pregame_action(match_id, &format!("lock/{}", agent_id)).await?.json().await?)
+    }
+
+    /// Leave agent select (dodge). Incurs the usual dodge penalties.
+    pub async fn pregame_quit(&self, match_id: &str) -> Result<(), ValorantError> {
+        self.pregame_action(match_id, "quit").await?;
+        Ok(())
+    }
+
+    async fn pregame_action(&self, match_id: &str, action: &str) -> Result<reqwest::Response, ValorantError> {
+        let url = format!("{}/pregame/v1/matches/{}/{}", self.glz_url().await, match_id, action);
+        let resp = self.send_authed(|h| self.http.post(&url).headers(h)).await?;
+        if resp.status() == 404 { return Err(ValorantError::NotInMatch); }
+        check_status(resp).await
     }
 }
@@ -0,0 +1,49 @@
+use crate::client::{check_status, ValorantClient};
+use crate::error::ValorantError;
+use crate::models::inventory::{Inventory, ItemType, OwnedItems};
+use crate::models::store::{StoreOffers, Storefront, Wallet};
+
+impl ValorantClient {
+    /// Daily offers, featured bundles and night market.
+    pub async fn storefront(&self, puuid: &str) -> Result<Storefront, ValorantError> {
+        let url = format!("{}/store/v3/storefront/{}", self.pd_url().await, puuid);
+        let resp = self.send_authed(|h| self.http.post(&url).headers(h).json(&serde_json::json!({}))).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// VP, Radianite and Kingdom Credits balances.
+    pub async fn wallet(&self, puuid: &str) -> Result<Wallet, ValorantError> {
+        let url = format!("{}/store/v1/wallet/{}", self.pd_url().await, puuid);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Prices of every purchasable item.
+    pub async fn store_offers(&self) -> Result<StoreOffers, ValorantError> {
+        let url = format!("{}/store/v1/offers/", self.pd_url().await);
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Entitlements of one item type.
+    pub async fn owned_items(&self, puuid: &str, item_type: ItemType) -> Result<OwnedItems, ValorantError> {
+        let url = format!("{}/store/v1/entitlements/{}/{}", self.pd_url().await, puuid, item_type.id());
+        let resp = self.send_authed(|h| self.http.get(&url).headers(h)).await?;
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Every item type at once.
+    pub async fn inventory(&self, puuid: &str) -> Result<Inventory, ValorantError> {
+        let (agents, skin_levels, skin_chromas, buddies, sprays, player_cards, player_titles, contracts) = tokio::try_join!(
+            self.owned_items(puuid, ItemType::Agents),
+            self.owned_items(puuid, ItemType::SkinLevels),
+            self.owned_items(puuid, ItemType::SkinChromas),
+            self.owned_items(puuid, ItemType::Buddies),
+            self.owned_items(puuid, ItemType::Sprays),
+            self.owned_items(puuid, ItemType::PlayerCards),
+            self.owned_items(puuid, ItemType::PlayerTitles),
+            self.owned_items(puuid, ItemType::Contracts),
+        )?;
+        Ok(Inventory { agents, skin_levels, skin_chromas, buddies, sprays, player_cards, player_titles, contracts })
+    }
+}
@@ -8,6 +8,10 @@ pub enum ValorantError {
     LockfileMalformed,
     #[error("Auth failed: {0}")]
     AuthFailed(String),
+    #[error("Could not determine region/shard: {0} — set VAL_REGION and VAL_SHARD")]
+    RegionUnknown(String),
+    #[error("Client version {0:?} is not a valid header value")]
+    InvalidClientVersion(String),
     #[error("Not in a match")]
     NotInMatch,
     #[error("HTTP error: {0}")]
@@ -1,11 +1,27 @@
+pub mod analytics;
 pub mod auth;
+pub mod builder;
+mod cache;
 pub mod client;
 pub mod endpoints;
 pub mod error;
+pub mod lockfile_watcher;
 pub mod log_watcher;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod models;
+pub mod phase_tracker;
+pub mod premade;
+pub mod profile;
+pub mod resolver;
+pub mod websocket;
 
+pub use builder::ValorantClientBuilder;
 pub use client::ValorantClient;
 pub use error::ValorantError;
+pub use lockfile_watcher::ConnectionState;
 pub use log_watcher::{LogEvent, LogWatcher};
 pub use models::player::NameEntry;
+pub use phase_tracker::{GamePhase, PhaseChange, PhaseState, PhaseTracker};
+pub use resolver::{EndpointResolver, RiotEndpoints, StaticEndpoints};
+pub use websocket::{LocalEvent, LocalEventType};
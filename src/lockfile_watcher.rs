@@ -0,0 +1,115 @@
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+
+use crate::builder::is_header_value;
+use crate::auth::LockfileData;
+use crate::client::{fetch_auth, fetch_client_version, ValorantClient};
+
+/// Wait this long after a lockfile change so the Riot Client can finish writing it.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+/// The lockfile appears before the local API is ready to hand out tokens.
+const RECONNECT_ATTEMPTS: u32 = 20;
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionState {
+    Disconnected,
+    Connecting,
+    Connected,
+}
+
+impl ValorantClient {
+    /// Current connection state to the Riot Client.
+    pub async fn connection_state(&self) -> ConnectionState {
+        *self.connection.read().await
+    }
+
+    /// Receive an event every time the connection state changes.
+    pub fn subscribe_connection(&self) -> broadcast::Receiver<ConnectionState> {
+        self.connection_tx.subscribe()
+    }
+
+    pub(crate) async fn set_connection_state(&self, state: ConnectionState) {
+        let mut current = self.connection.write().await;
+        if *current != state {
+            *current = state;
+            let _ = self.connection_tx.send(state);
+        }
+    }
+
+    /// Watch the lockfile and reconnect whenever the Riot Client restarts
+    /// with a new port and password. Must be called from a Tokio runtime.
+    pub fn watch_lockfile(&self) -> Result<(), anyhow::Error> {
+        let path = self.lockfile_path.clone()
+            .ok_or(anyhow::anyhow!("Client was built without a lockfile path"))?;
+        let dir = path.parent()
+            .ok_or(anyhow::anyhow!("Lockfile has no parent directory"))?
+            .to_path_buf();
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<Event>();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                let _ = tx.send(event);
+            }
+        })?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+        let client = self.clone();
+        tokio::spawn(async move {
+            // Dropping the watcher stops it, so it lives as long as the task.
+            let _watcher = watcher;
+            while let Some(event) = rx.recv().await {
+                if matches!(event.kind, EventKind::Access(_))
+                    || !event.paths.iter().any(|p| p.file_name() == path.file_name())
+                {
+                    continue;
+                }
+                tokio::time::sleep(DEBOUNCE).await;
+                while rx.try_recv().is_ok() {}
+                client.reload_lockfile(&path).await;
+            }
+        });
+
+        Ok(())
+    }
+
+    async fn reload_lockfile(&self, path: &Path) {
+        let lockfile = match LockfileData::read_from(path) {
+            Ok(lockfile) => lockfile,
+            Err(_) => {
+                self.set_connection_state(ConnectionState::Disconnected).await;
+                return;
+            }
+        };
+        if *self.lockfile.read().await == lockfile
+            && self.connection_state().await == ConnectionState::Connected
+        {
+            return;
+        }
+
+        self.set_connection_state(ConnectionState::Connecting).await;
+        for _ in 0..RECONNECT_ATTEMPTS {
+            if !path.exists() {
+                break;
+            }
+            if let Ok(auth) = fetch_auth(&self.http, &*self.endpoints, &lockfile, &self.overrides).await {
+                if self.overrides.client_version.is_none() {
+                    let version = fetch_client_version(&self.http, &*self.endpoints, &lockfile).await;
+                    if let Some(version) = version.ok().filter(|v| is_header_value(v)) {
+                        *self.client_version.write().await = version;
+                    }
+                }
+                *self.lockfile.write().await = lockfile;
+                *self.auth.write().await = auth;
+                self.set_connection_state(ConnectionState::Connected).await;
+                return;
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+        self.set_connection_state(ConnectionState::Disconnected).await;
+    }
+}
@@ -2,6 +2,20 @@
 use tokio::sync::broadcast;
 use std::path::PathBuf;
 
+use crate::builder::{env_var, ENV_LOG_PATH};
+use crate::client::ValorantClient;
+
+/// `VAL_LOG_PATH` if set, otherwise the default `ShooterGame.log` location.
+pub fn default_log_path() -> Result<PathBuf, anyhow::Error> {
+    if let Some(path) = env_var(ENV_LOG_PATH) {
+        return Ok(PathBuf::from(path));
+    }
+    let path = dirs::data_local_dir()
+        .ok_or(anyhow::anyhow!("No data dir"))?
+        .join("VALORANT/Saved/Logs/ShooterGame.log");
+    Ok(path)
+}
+
 #[derive(Debug, Clone)]
 pub enum LogEvent {
     RoundEnded { round_num: u32 },
@@ -13,12 +27,31 @@ pub enum LogEvent {
 
 pub struct LogWatcher {
     sender: broadcast::Sender<LogEvent>,
+    path: Option<PathBuf>,
+}
+
+impl ValorantClient {
+    /// A `LogWatcher` for the log path set with `ValorantClientBuilder::log_path`
+    /// or `VAL_LOG_PATH`, falling back to the default location.
+    pub fn log_watcher(&self) -> (LogWatcher, broadcast::Receiver<LogEvent>) {
+        match &self.overrides.log_path {
+            Some(path) => LogWatcher::with_path(path),
+            None => LogWatcher::new(),
+        }
+    }
 }
 
 impl LogWatcher {
     pub fn new() -> (Self, broadcast::Receiver<LogEvent>) {
         let (tx, rx) = broadcast::channel(64);
-        (Self { sender: tx }, rx)
+        (Self { sender: tx, path: None }, rx)
+    }
+
+    /// Tail `ShooterGame.log` at this path instead of the default location.
+    pub fn with_path(path: impl Into<PathBuf>) -> (Self, broadcast::Receiver<LogEvent>) {
+        let (mut watcher, rx) = Self::new();
+        watcher.path = Some(path.into());
+        (watcher, rx)
     }
 
     pub fn subscribe(&self) -> broadcast::Receiver<LogEvent> {
@@ -30,7 +63,7 @@ impl LogWatcher {
     }
 
     pub fn start(&self) -> Result<(), anyhow::Error> {
-        let log_path = Self::log_path()?;
+        let log_path = self.log_path()?;
         let sender = self.sender.clone();
 
         tokio::spawn(async move {
@@ -40,11 +73,12 @@ impl LogWatcher {
         Ok(())
     }
 
-    fn log_path() -> Result<PathBuf, anyhow::Error> {
-        let path = dirs::data_local_dir()
-            .ok_or(anyhow::anyhow!("No data dir"))?
-            .join("VALORANT/Saved/Logs/ShooterGame.log");
-        Ok(path)
+    /// Explicit path, then `VAL_LOG_PATH`, then the default install location.
+    fn log_path(&self) -> Result<PathBuf, anyhow::Error> {
+        match &self.path {
+            Some(path) => Ok(path.clone()),
+            None => default_log_path(),
+        }
     }
 
     async fn tail_file(path: PathBuf, sender: broadcast::Sender<LogEvent>) {
@@ -91,10 +125,9 @@ impl LogWatcher {
             } else { "unknown".to_string() };
             return Some(LogEvent::MatchEnded { winning_team: team });
         }
-        if line.contains("_PostDeath_PC") && line.contains("AcknowledgePawn") && !line.contains("PrevPawn") {
-            if line.contains("ClientRestart_Implementation") {
-                return Some(LogEvent::PlayerDied);
-            }
+        if line.contains("_PostDeath_PC") && line.contains("AcknowledgePawn") && !line.contains("PrevPawn")
+            && line.contains("ClientRestart_Implementation") {
+            return Some(LogEvent::PlayerDied);
         }
         if line.contains("BombInteractionBuff_C") {
             let agent = line.split("InternalOnActiveGameplayEffectAdded ").nth(1)?
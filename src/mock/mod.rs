@@ -0,0 +1,340 @@
+//! Offline stand-in for the Riot Client and PD/GLZ clusters, serving recorded
+//! fixtures so the client can be exercised without Valorant running.
+//!
+//! ```no_run
+//! # async fn demo() -> Result<(), Box<dyn std::error::Error>> {
+//! let mock = val_local_api::mock::MockServer::start().await?;
+//! let client = mock.client().await?;
+//! let history = client.match_history(&client.puuid().await, 10).await?;
+//! # Ok(()) }
+//! ```
+
+use axum::{
+    body::Body,
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    http::{Method, Request, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use base64::Engine;
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use crate::auth::LockfileData;
+use crate::client::ValorantClient;
+use crate::error::ValorantError;
+use crate::resolver::StaticEndpoints;
+
+/// PUUID of the signed-in player in every fixture.
+pub const MOCK_PUUID: &str = "5c0b1d2e-8f3a-4e6b-9a1c-3d2e4f5a6b7c";
+pub const MOCK_PASSWORD: &str = "mock-password";
+
+/// `(method, path pattern, fixture)`. `*` matches one path segment.
+const FIXTURES: &[(&str, &str, &str)] = &[
+    ("GET", "/product-session/v1/external-sessions", include_str!("fixtures/external_sessions.json")),
+    ("GET", "/chat/v4/presences", include_str!("fixtures/presences.json")),
+    ("GET", "/chat/v4/friends", include_str!("fixtures/friends.json")),
+    ("DELETE", "/chat/v4/friends", "{}"),
+    ("GET", "/chat/v4/friendrequests", include_str!("fixtures/friend_requests.json")),
+    ("POST", "/chat/v4/friendrequests", "{}"),
+    ("GET", "/chat/v6/conversations", include_str!("fixtures/conversations.json")),
+    ("GET", "/chat/v6/messages", include_str!("fixtures/chat_messages.json")),
+    ("POST", "/chat/v6/messages", include_str!("fixtures/chat_message_sent.json")),
+    ("GET", "/riotclient/region-locale", include_str!("fixtures/region_locale.json")),
+    ("PUT", "/name-service/v2/players", include_str!("fixtures/name_service.json")),
+    ("GET", "/match-history/v1/history/*", include_str!("fixtures/match_history.json")),
+    ("GET", "/match-details/v1/matches/*", include_str!("fixtures/match_details.json")),
+    ("GET", "/account-xp/v1/players/*", include_str!("fixtures/account_xp.json")),
+    ("GET", "/mmr/v1/players/*", include_str!("fixtures/mmr.json")),
+    ("GET", "/mmr/v1/players/*/competitiveupdates", include_str!("fixtures/competitive_updates.json")),
+    ("GET", "/mmr/v1/leaderboards/affinity/*/queue/competitive/season/*", include_str!("fixtures/leaderboard.json")),
+    ("POST", "/store/v3/storefront/*", include_str!("fixtures/storefront.json")),
+    ("GET", "/store/v1/wallet/*", include_str!("fixtures/wallet.json")),
+    ("GET", "/store/v1/offers", include_str!("fixtures/store_offers.json")),
+    ("GET", "/content-service/v3/content", include_str!("fixtures/content.json")),
+    ("GET", "/contracts/v1/contracts/*", include_str!("fixtures/contracts.json")),
+    ("GET", "/contract-definitions/v2/definitions", include_str!("fixtures/contract_definitions.json")),
+    ("GET", "/store/v1/entitlements/*/01bb38e1-da47-4e6a-9b3d-945fe4655707", include_str!("fixtures/entitlements_agents.json")),
+    ("GET", "/store/v1/entitlements/*/e7c63390-eda7-46e0-bb7a-a6abdacd2433", include_str!("fixtures/entitlements_skin_levels.json")),
+    ("GET", "/store/v1/entitlements/*/3ad1b2b2-acdb-4524-852f-954a76ddae0a", include_str!("fixtures/entitlements_skin_chromas.json")),
+    ("GET", "/store/v1/entitlements/*/dd3bf334-87f3-40bd-b043-682a57a8dc3a", include_str!("fixtures/entitlements_buddies.json")),
+    ("GET", "/store/v1/entitlements/*/d5f120f8-ff8c-4aac-92ea-f2b5acbe9475", include_str!("fixtures/entitlements_sprays.json")),
+    ("GET", "/store/v1/entitlements/*/3f296c07-64c3-494c-923b-fe692a4fa1bd", include_str!("fixtures/entitlements_cards.json")),
+    ("GET", "/store/v1/entitlements/*/de7caa6b-adf7-4588-bbd1-143831e786c6", include_str!("fixtures/entitlements_titles.json")),
+    ("GET", "/store/v1/entitlements/*/f85cb6f7-33e5-4dc8-b609-ec7212301948", include_str!("fixtures/entitlements_contracts.json")),
+    ("GET", "/personalization/v2/players/*/playerloadout", include_str!("fixtures/player_loadout.json")),
+    ("PUT", "/personalization/v2/players/*/playerloadout", include_str!("fixtures/player_loadout.json")),
+    ("GET", "/core-game/v1/players/*", include_str!("fixtures/coregame_player.json")),
+    ("GET", "/core-game/v1/matches/*", include_str!("fixtures/coregame_match.json")),
+    ("GET", "/core-game/v1/matches/*/loadouts", include_str!("fixtures/coregame_loadouts.json")),
+    ("GET", "/pregame/v1/players/*", include_str!("fixtures/pregame_player.json")),
+    ("GET", "/pregame/v1/matches/*", include_str!("fixtures/pregame_match.json")),
+    ("POST", "/pregame/v1/matches/*/select/*", include_str!("fixtures/pregame_match.json")),
+    ("POST", "/pregame/v1/matches/*/lock/*", include_str!("fixtures/pregame_match.json")),
+    ("POST", "/pregame/v1/matches/*/quit", "{}"),
+    ("GET", "/parties/v1/players/*", include_str!("fixtures/party_player.json")),
+    ("GET", "/parties/v1/parties/*", include_str!("fixtures/party.json")),
+    ("POST", "/parties/v1/parties/*/accessibility", include_str!("fixtures/party.json")),
+    ("POST", "/parties/v1/parties/*/queue", include_str!("fixtures/party.json")),
+    ("POST", "/parties/v1/parties/*/invites/name/*/tag/*", include_str!("fixtures/party.json")),
+    ("POST", "/parties/v1/parties/*/matchmaking/join", include_str!("fixtures/party.json")),
+    ("POST", "/parties/v1/parties/*/matchmaking/leave", include_str!("fixtures/party.json")),
+    ("POST", "/parties/v1/parties/*/members/*/setReady", include_str!("fixtures/party.json")),
+];
+
+#[derive(Debug, Clone)]
+struct Fixture {
+    method: Method,
+    pattern: String,
+    status: StatusCode,
+    body: Value,
+    /// Requests left before the fixture is removed; `None` serves forever.
+    remaining: Option<u32>,
+}
+
+impl Fixture {
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        if self.method != method {
+            return false;
+        }
+        let mut want = self.pattern.trim_matches('/').split('/');
+        let mut got = path.trim_matches('/').split('/');
+        loop {
+            match (want.next(), got.next()) {
+                (None, None) => return true,
+                (Some("*"), Some(_)) => {}
+                (Some(w), Some(g)) if w == g => {}
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// A local HTTP server answering every wrapped endpoint from fixtures.
+/// Stopped when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    fixtures: Arc<RwLock<Vec<Fixture>>>,
+    requests: Arc<RwLock<Vec<(Method, String)>>>,
+    sockets: broadcast::Sender<SocketCommand>,
+    subscriptions: Arc<RwLock<Vec<String>>>,
+    task: JoinHandle<()>,
+}
+
+/// Sent to every open local WebSocket.
+#[derive(Debug, Clone)]
+enum SocketCommand {
+    Event { event: String, payload: Value },
+    Disconnect,
+}
+
+impl MockServer {
+    /// Bind to an ephemeral port on 127.0.0.1 and start serving.
+    pub async fn start() -> std::io::Result<Self> {
+        let mut fixtures: Vec<Fixture> = FIXTURES.iter()
+            .map(|(method, pattern, body)| Fixture {
+                method: method.parse().expect("valid fixture method"),
+                pattern: pattern.to_string(),
+                status: StatusCode::OK,
+                body: serde_json::from_str(body).expect("valid fixture JSON"),
+                remaining: None,
+            })
+            .collect();
+        fixtures.push(Fixture {
+            method: Method::GET,
+            pattern: "/entitlements/v1/token".into(),
+            status: StatusCode::OK,
+            body: token_response(Duration::from_secs(3600)),
+            remaining: None,
+        });
+        let fixtures = Arc::new(RwLock::new(fixtures));
+        let requests = Arc::new(RwLock::new(Vec::new()));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let (sockets, _) = broadcast::channel(64);
+        let subscriptions = Arc::new(RwLock::new(Vec::new()));
+        let app = Router::new()
+            .route("/", get({
+                let sockets = sockets.clone();
+                let subscriptions = subscriptions.clone();
+                move |ws: WebSocketUpgrade| async move {
+                    ws.on_upgrade(move |socket| serve_socket(socket, sockets.subscribe(), subscriptions))
+                }
+            }))
+            .fallback({
+                let fixtures = fixtures.clone();
+                let requests = requests.clone();
+                move |req: Request<Body>| serve(fixtures.clone(), requests.clone(), req)
+            });
+        let task = tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        Ok(Self { addr, fixtures, requests, sockets, subscriptions, task })
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Routes every cluster to this server.
+    pub fn endpoints(&self) -> StaticEndpoints {
+        StaticEndpoints::all(self.url())
+    }
+
+    pub fn lockfile(&self) -> LockfileData {
+        LockfileData {
+            port: self.addr.port(),
+            password: MOCK_PASSWORD.into(),
+            protocol: "http".into(),
+        }
+    }
+
+    /// A client connected to this server.
+    pub async fn client(&self) -> Result<ValorantClient, ValorantError> {
+        ValorantClient::builder()
+            .lockfile(self.lockfile())
+            .endpoints(self.endpoints())
+            .build().await
+    }
+
+    /// Serve `body` for `method` + `pattern`, replacing any existing fixture.
+    pub fn set_fixture(&self, method: Method, pattern: &str, body: Value) {
+        self.set(method, pattern, StatusCode::OK, body);
+    }
+
+    /// Answer `method` + `pattern` with an error status, e.g. 404 for "not in match".
+    pub fn set_status(&self, method: Method, pattern: &str, status: StatusCode) {
+        let body = json!({ "httpStatus": status.as_u16(), "errorCode": "MOCK_ERROR", "message": "mock error" });
+        self.set(method, pattern, status, body);
+    }
+
+    /// Answer the next request to `method` + `pattern` with an error status,
+    /// then go back to the previous fixture, e.g. a 401 for an expired token.
+    pub fn set_status_once(&self, method: Method, pattern: &str, status: StatusCode) {
+        let body = json!({ "httpStatus": status.as_u16(), "errorCode": "MOCK_ERROR", "message": "mock error" });
+        self.fixtures.write().unwrap().insert(0, Fixture {
+            method, pattern: pattern.to_string(), status, body, remaining: Some(1),
+        });
+    }
+
+    /// Hand out access tokens that expire `expires_in` from now.
+    pub fn set_token_expiry(&self, expires_in: Duration) {
+        self.set_fixture(Method::GET, "/entitlements/v1/token", token_response(expires_in));
+    }
+
+    /// How many requests so far matched `method` + `pattern`.
+    pub fn request_count(&self, method: Method, pattern: &str) -> usize {
+        let probe = Fixture { method, pattern: pattern.to_string(), status: StatusCode::OK, body: Value::Null, remaining: None };
+        self.requests.read().unwrap().iter().filter(|(m, path)| probe.matches(m, path)).count()
+    }
+
+    /// Push a local API event to every WebSocket subscribed to `event`.
+    pub fn push_event(&self, event: &str, uri: &str, event_type: &str, data: Value) {
+        let payload = json!({ "data": data, "eventType": event_type, "uri": uri });
+        let _ = self.sockets.send(SocketCommand::Event { event: event.to_string(), payload });
+    }
+
+    /// Drop every open WebSocket, as the Riot Client does when it restarts.
+    pub fn disconnect_websockets(&self) {
+        self.subscriptions.write().unwrap().clear();
+        let _ = self.sockets.send(SocketCommand::Disconnect);
+    }
+
+    /// Wait until a WebSocket client has subscribed to `event`.
+    pub async fn wait_for_subscription(&self, event: &str) {
+        while !self.subscriptions.read().unwrap().iter().any(|s| s == event) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    fn set(&self, method: Method, pattern: &str, status: StatusCode, body: Value) {
+        let mut fixtures = self.fixtures.write().unwrap();
+        fixtures.retain(|f| !(f.method == method && f.pattern == pattern));
+        // Newest first so overrides win over broader patterns.
+        fixtures.insert(0, Fixture { method, pattern: pattern.to_string(), status, body, remaining: None });
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve(fixtures: Arc<RwLock<Vec<Fixture>>>, requests: Arc<RwLock<Vec<(Method, String)>>>, req: Request<Body>) -> Response {
+    requests.write().unwrap().push((req.method().clone(), req.uri().path().to_string()));
+    let fixture = {
+        let mut fixtures = fixtures.write().unwrap();
+        let index = fixtures.iter().position(|f| f.matches(req.method(), req.uri().path()));
+        index.map(|i| match fixtures[i].remaining {
+            Some(n) if n <= 1 => fixtures.remove(i),
+            Some(n) => {
+                fixtures[i].remaining = Some(n - 1);
+                fixtures[i].clone()
+            }
+            None => fixtures[i].clone(),
+        })
+    };
+    match fixture {
+        Some(f) => (f.status, Json(f.body)).into_response(),
+        None => (StatusCode::NOT_FOUND, Json(json!({
+            "httpStatus": 404,
+            "errorCode": "RESOURCE_NOT_FOUND",
+            "message": format!("no fixture for {} {}", req.method(), req.uri().path()),
+        }))).into_response(),
+    }
+}
+
+/// Speak just enough WAMP for `subscribe_events`: record `[5, event]`
+/// subscriptions and forward matching pushes as `[8, event, payload]`.
+async fn serve_socket(mut socket: WebSocket, mut commands: broadcast::Receiver<SocketCommand>, subscriptions: Arc<RwLock<Vec<String>>>) {
+    let mut subscribed: Vec<String> = Vec::new();
+    loop {
+        tokio::select! {
+            message = socket.recv() => {
+                let Some(Ok(Message::Text(text))) = message else { return };
+                if let Ok((5, event)) = serde_json::from_str::<(u8, String)>(&text) {
+                    subscriptions.write().unwrap().push(event.clone());
+                    subscribed.push(event);
+                }
+            }
+            command = commands.recv() => match command {
+                Ok(SocketCommand::Event { event, payload }) => {
+                    if subscribed.iter().any(|s| event.starts_with(s.as_str())) {
+                        let frame = json!([8, event, payload]).to_string();
+                        if socket.send(Message::Text(frame)).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Ok(SocketCommand::Disconnect) | Err(_) => return,
+            },
+        }
+    }
+}
+
+/// Entitlements response with an unsigned JWT that expires after `expires_in`.
+fn token_response(expires_in: Duration) -> Value {
+    let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let exp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() + expires_in.as_secs();
+    let header = engine.encode(json!({ "alg": "none" }).to_string());
+    let claims = engine.encode(json!({
+        "sub": MOCK_PUUID,
+        "exp": exp,
+        "acct": { "type": 0, "state": "ENABLED", "adm": false, "game_name": "houdyxx", "tag_line": "ALTF4" },
+    }).to_string());
+    json!({
+        "accessToken": format!("{}.{}.", header, claims),
+        "entitlements": [],
+        "issuer": "https://entitlements.auth.riotgames.com",
+        "subject": MOCK_PUUID,
+        "token": format!("{}.{}.", header, claims),
+    })
+}
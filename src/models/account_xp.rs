@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::match_data::MatchHistoryEntry;
+
+/// XP needed for each account level.
+pub const ACCOUNT_LEVEL_XP: u32 = 5000;
+
+/// Account level progress from `account-xp/v1/players/{puuid}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountXp {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    #[serde(rename = "Version")]
+    pub version: u64,
+    #[serde(rename = "Progress")]
+    pub progress: XpProgress,
+    /// Newest first.
+    #[serde(rename = "History", default)]
+    pub history: Vec<XpHistoryEntry>,
+    #[serde(rename = "LastTimeGrantedFirstWin")]
+    pub last_first_win: Option<String>,
+    #[serde(rename = "NextTimeFirstWinAvailable")]
+    pub next_first_win_available: Option<String>,
+}
+
+impl AccountXp {
+    pub fn for_match(&self, match_id: &str) -> Option<&XpHistoryEntry> {
+        self.history.iter().find(|e| e.match_id == match_id)
+    }
+
+    /// Pair each match with the XP it granted, if the XP history still covers it.
+    pub fn correlate<'a>(&'a self, matches: &'a [MatchHistoryEntry]) -> Vec<(&'a MatchHistoryEntry, Option<&'a XpHistoryEntry>)> {
+        matches.iter().map(|m| (m, self.for_match(&m.match_id))).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XpProgress {
+    #[serde(rename = "Level")]
+    pub level: u32,
+    /// XP into the current level.
+    #[serde(rename = "XP")]
+    pub xp: u32,
+}
+
+impl XpProgress {
+    pub fn xp_to_next_level(&self) -> u32 {
+        ACCOUNT_LEVEL_XP.saturating_sub(self.xp)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XpHistoryEntry {
+    #[serde(rename = "ID")]
+    pub match_id: String,
+    #[serde(rename = "MatchStart")]
+    pub match_start: String,
+    #[serde(rename = "StartProgress")]
+    pub start: XpProgress,
+    #[serde(rename = "EndProgress")]
+    pub end: XpProgress,
+    #[serde(rename = "XPDelta")]
+    pub xp_delta: u32,
+    #[serde(rename = "XPSources", default)]
+    pub sources: Vec<XpSource>,
+    #[serde(rename = "XPMultipliers", default)]
+    pub multipliers: Vec<serde_json::Value>,
+}
+
+impl XpHistoryEntry {
+    pub fn levels_gained(&self) -> u32 {
+        self.end.level.saturating_sub(self.start.level)
+    }
+
+    pub fn xp_from(&self, kind: &XpSourceKind) -> u32 {
+        self.sources.iter().filter(|s| &s.kind == kind).map(|s| s.amount).sum()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XpSource {
+    #[serde(rename = "ID")]
+    pub kind: XpSourceKind,
+    #[serde(rename = "Amount")]
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum XpSourceKind {
+    TimePlayed,
+    MatchWin,
+    FirstWinOfTheDay,
+    Other(String),
+}
+
+impl XpSourceKind {
+    pub fn id(&self) -> &str {
+        match self {
+            XpSourceKind::TimePlayed => "time-played",
+            XpSourceKind::MatchWin => "match-win",
+            XpSourceKind::FirstWinOfTheDay => "first-win-of-the-day",
+            XpSourceKind::Other(id) => id,
+        }
+    }
+}
+
+impl From<String> for XpSourceKind {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            "time-played" => XpSourceKind::TimePlayed,
+            "match-win" => XpSourceKind::MatchWin,
+            "first-win-of-the-day" => XpSourceKind::FirstWinOfTheDay,
+            _ => XpSourceKind::Other(id),
+        }
+    }
+}
+
+impl From<XpSourceKind> for String {
+    fn from(kind: XpSourceKind) -> Self {
+        kind.id().to_string()
+    }
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+/// Which chat a conversation belongs to, derived from its ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatChannel {
+    Party,
+    /// Agent select, own team only.
+    Pregame,
+    /// In-game team chat.
+    Team,
+    /// In-game all chat.
+    All,
+    /// Whisper with a single player.
+    Direct,
+    Other,
+}
+
+impl ChatChannel {
+    /// Classify a conversation ID, e.g. `{match_id}all@ares-coregame.eu1.pvp.net`.
+    pub fn from_cid(cid: &str) -> Self {
+        let Some((local, domain)) = cid.split_once('@') else { return ChatChannel::Other };
+        match domain.split('.').next() {
+            Some("ares-parties") => ChatChannel::Party,
+            Some("ares-pregame") => ChatChannel::Pregame,
+            Some("ares-coregame") if local.ends_with("all") => ChatChannel::All,
+            Some("ares-coregame") => ChatChannel::Team,
+            Some(region) if !region.starts_with("ares-") => ChatChannel::Direct,
+            _ => ChatChannel::Other,
+        }
+    }
+
+    /// `type` the local API expects when sending to this channel.
+    pub(crate) fn message_type(&self) -> &'static str {
+        match self {
+            ChatChannel::Direct => "chat",
+            _ => "groupchat",
+        }
+    }
+}
+
+/// Response of the local `chat/v6/conversations` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conversations {
+    #[serde(rename = "conversations", default)]
+    pub conversations: Vec<Conversation>,
+}
+
+impl Conversations {
+    /// The first conversation on `channel`, e.g. the current all chat.
+    pub fn channel(&self, channel: ChatChannel) -> Option<&Conversation> {
+        self.conversations.iter().find(|c| c.channel() == channel)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conversation {
+    #[serde(rename = "cid")]
+    pub cid: String,
+    /// `chat` for whispers, `groupchat` for party and match chats.
+    #[serde(rename = "type")]
+    pub conversation_type: String,
+    #[serde(rename = "direct_messages", default)]
+    pub direct_messages: bool,
+    #[serde(rename = "muted", default)]
+    pub muted: bool,
+    #[serde(rename = "unread_count", default)]
+    pub unread_count: u32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Conversation {
+    pub fn channel(&self) -> ChatChannel {
+        ChatChannel::from_cid(&self.cid)
+    }
+}
+
+/// Response of the local `chat/v6/messages` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessages {
+    #[serde(rename = "messages", default)]
+    pub messages: Vec<ChatMessage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    #[serde(rename = "id")]
+    pub id: String,
+    #[serde(rename = "cid")]
+    pub cid: String,
+    #[serde(rename = "body")]
+    pub body: String,
+    /// Sender.
+    #[serde(rename = "puuid")]
+    pub puuid: String,
+    #[serde(rename = "game_name")]
+    pub game_name: String,
+    #[serde(rename = "game_tag")]
+    pub game_tag: String,
+    /// Unix time in milliseconds.
+    #[serde(rename = "time", deserialize_with = "millis")]
+    pub time: u64,
+    #[serde(rename = "read", default)]
+    pub read: bool,
+    #[serde(rename = "type")]
+    pub message_type: String,
+}
+
+impl ChatMessage {
+    pub fn channel(&self) -> ChatChannel {
+        ChatChannel::from_cid(&self.cid)
+    }
+
+    /// `name#tag` of the sender.
+    pub fn sender(&self) -> String {
+        format!("{}#{}", self.game_name, self.game_tag)
+    }
+}
+
+/// The local API sends timestamps as strings.
+fn millis<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Millis {
+        Number(u64),
+        Text(String),
+    }
+    match Millis::deserialize(deserializer)? {
+        Millis::Number(ms) => Ok(ms),
+        Millis::Text(text) => text.parse().map_err(serde::de::Error::custom),
+    }
+}
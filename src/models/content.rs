@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Content metadata from `content-service/v3/content`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Content {
+    #[serde(rename = "Seasons", default)]
+    pub seasons: Vec<Season>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Content {
+    /// Acts in chronological order.
+    pub fn acts(&self) -> Vec<&Season> {
+        let mut acts: Vec<&Season> = self.seasons.iter().filter(|s| s.is_act()).collect();
+        acts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+        acts
+    }
+
+    pub fn current_act(&self) -> Option<&Season> {
+        self.seasons.iter().find(|s| s.is_act() && s.is_active)
+    }
+
+    /// The act before the current one.
+    pub fn previous_act(&self) -> Option<&Season> {
+        let acts = self.acts();
+        let current = acts.iter().position(|s| s.is_active)?;
+        current.checked_sub(1).map(|i| acts[i])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Season {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// `episode` or `act`.
+    #[serde(rename = "Type")]
+    pub season_type: String,
+    /// RFC 3339; compares correctly as a string.
+    #[serde(rename = "StartTime")]
+    pub start_time: String,
+    #[serde(rename = "EndTime")]
+    pub end_time: String,
+    #[serde(rename = "IsActive")]
+    pub is_active: bool,
+}
+
+impl Season {
+    pub fn is_act(&self) -> bool {
+        self.season_type == "act"
+    }
+}
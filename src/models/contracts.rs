@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Highest battlepass tier, including the epilogue.
+pub const BATTLEPASS_MAX_TIER: u32 = 55;
+
+/// XP needed to reach `tier` from the tier below it. Tier 1 is free;
+/// tiers 2–50 start at 2000 and grow by 750, epilogue tiers cost 36500.
+pub fn battlepass_tier_xp(tier: u32) -> Option<u32> {
+    match tier {
+        1 => Some(0),
+        2..=50 => Some(2000 + 750 * (tier - 2)),
+        51..=BATTLEPASS_MAX_TIER => Some(36500),
+        _ => None,
+    }
+}
+
+/// Contract and mission progress from `contracts/v1/contracts/{puuid}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contracts {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    #[serde(rename = "Version")]
+    pub version: u64,
+    #[serde(rename = "Contracts")]
+    pub contracts: Vec<Contract>,
+    /// The agent contract currently being progressed.
+    #[serde(rename = "ActiveSpecialContract")]
+    pub active_special_contract: Option<String>,
+    #[serde(rename = "Missions", default)]
+    pub missions: Vec<Mission>,
+    #[serde(rename = "MissionMetadata")]
+    pub mission_metadata: MissionMetadata,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+    /// When this response was decoded; dailies expire within a day of it.
+    #[serde(skip, default = "SystemTime::now")]
+    pub fetched_at: SystemTime,
+}
+
+impl Contracts {
+    pub fn contract(&self, definition_id: &str) -> Option<&Contract> {
+        self.contracts.iter().find(|c| c.definition_id == definition_id)
+    }
+
+    pub fn active_special(&self) -> Option<&Contract> {
+        self.active_special_contract.as_deref().and_then(|id| self.contract(id))
+    }
+
+    /// Missions that reset with the weekly refill.
+    pub fn weekly_missions(&self) -> impl Iterator<Item = &Mission> {
+        self.missions.iter().filter(|m| self.is_weekly(m))
+    }
+
+    /// Missions that expire within a day of `fetched_at`. Onboarding and
+    /// event missions run longer and are neither daily nor weekly.
+    pub fn daily_missions(&self) -> impl Iterator<Item = &Mission> {
+        self.missions.iter().filter(|m| !self.is_weekly(m) && self.is_daily(m))
+    }
+
+    fn is_daily(&self, mission: &Mission) -> bool {
+        parse_utc(&mission.expiration_time)
+            .is_some_and(|expires| expires <= self.fetched_at + DAY)
+    }
+
+    fn is_weekly(&self, mission: &Mission) -> bool {
+        self.mission_metadata.weekly_refill_time.as_deref()
+            .is_some_and(|refill| same_second(refill, &mission.expiration_time))
+    }
+}
+
+/// Compare two UTC timestamps to the second, so `...00Z`, `...00.000Z` and
+/// `...00z` all match.
+fn same_second(a: &str, b: &str) -> bool {
+    const SECONDS: usize = "2026-01-01T00:00:00".len();
+    match (a.get(..SECONDS), b.get(..SECONDS)) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        _ => false,
+    }
+}
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Parse a UTC timestamp like `2026-10-19T00:00:00Z`, ignoring fractional seconds.
+fn parse_utc(timestamp: &str) -> Option<SystemTime> {
+    let field = |range: std::ops::Range<usize>| timestamp.get(range)?.parse::<i64>().ok();
+    let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+    let (hour, minute, second) = (field(11..13)?, field(14..16)?, field(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // Days since 1970-01-01 in the proleptic Gregorian calendar.
+    let (y, m) = if month <= 2 { (year - 1, month + 9) } else { (year, month - 3) };
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * m + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    let seconds = days * 86_400 + hour * 3600 + minute * 60 + second;
+    u64::try_from(seconds).ok().map(|s| UNIX_EPOCH + Duration::from_secs(s))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contract {
+    #[serde(rename = "ContractDefinitionID")]
+    pub definition_id: String,
+    #[serde(rename = "ContractProgression")]
+    pub progression: ContractProgression,
+    #[serde(rename = "ProgressionLevelReached")]
+    pub level: u32,
+    /// XP earned since reaching `level`.
+    #[serde(rename = "ProgressionTowardsNextLevel")]
+    pub xp_into_level: u32,
+}
+
+impl Contract {
+    /// XP left to the next level, using the definition's per-level costs.
+    pub fn xp_to_next_level(&self, definition: &ContractDefinition) -> Option<u32> {
+        definition.level_xp(self.level + 1).map(|xp| xp.saturating_sub(self.xp_into_level))
+    }
+
+    /// XP left to the next tier when this contract is a battlepass.
+    pub fn battlepass_xp_to_next_tier(&self) -> Option<u32> {
+        battlepass_tier_xp(self.level + 1).map(|xp| xp.saturating_sub(self.xp_into_level))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractProgression {
+    #[serde(rename = "TotalProgressionEarned")]
+    pub total_xp: u64,
+    #[serde(rename = "HighestRewardedLevel", default)]
+    pub highest_rewarded_level: HashMap<String, RewardedLevel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardedLevel {
+    #[serde(rename = "Amount")]
+    pub amount: u32,
+    #[serde(rename = "Version")]
+    pub version: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mission {
+    #[serde(rename = "ID")]
+    pub id: String,
+    /// Objective ID → progress so far.
+    #[serde(rename = "Objectives", default)]
+    pub objectives: HashMap<String, u32>,
+    #[serde(rename = "Complete")]
+    pub complete: bool,
+    #[serde(rename = "ExpirationTime")]
+    pub expiration_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionMetadata {
+    #[serde(rename = "NPECompleted")]
+    pub npe_completed: bool,
+    #[serde(rename = "WeeklyCheckpoint")]
+    pub weekly_checkpoint: Option<String>,
+    #[serde(rename = "WeeklyRefillTime")]
+    pub weekly_refill_time: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractDefinitions {
+    #[serde(rename = "Definitions")]
+    pub definitions: Vec<ContractDefinition>,
+}
+
+impl ContractDefinitions {
+    pub fn find(&self, id: &str) -> Option<&ContractDefinition> {
+        self.definitions.iter().find(|d| d.id == id)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractDefinition {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "ProgressionSchedule")]
+    pub progression_schedule: ProgressionSchedule,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl ContractDefinition {
+    /// XP needed to reach `level` from the level below it.
+    pub fn level_xp(&self, level: u32) -> Option<u32> {
+        self.progression_schedule.deltas_per_level.get(level as usize).copied()
+    }
+
+    pub fn max_level(&self) -> u32 {
+        self.progression_schedule.deltas_per_level.len().saturating_sub(1) as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressionSchedule {
+    #[serde(rename = "ProgressionCurrencyID", default)]
+    pub currency_id: String,
+    /// Indexed by level; entry 0 is the free starting level.
+    #[serde(rename = "ProgressionDeltaPerLevel", default)]
+    pub deltas_per_level: Vec<u32>,
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Response of the local `chat/v4/friends` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Friends {
+    #[serde(rename = "friends", default)]
+    pub friends: Vec<Friend>,
+}
+
+impl Friends {
+    pub fn get(&self, puuid: &str) -> Option<&Friend> {
+        self.friends.iter().find(|f| f.puuid == puuid)
+    }
+
+    /// Find a friend by Riot ID, ignoring case like the client does.
+    pub fn find_by_riot_id(&self, name: &str, tag: &str) -> Option<&Friend> {
+        self.friends.iter().find(|f| f.game_name.eq_ignore_ascii_case(name) && f.game_tag.eq_ignore_ascii_case(tag))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Friend {
+    #[serde(rename = "puuid")]
+    pub puuid: String,
+    #[serde(rename = "game_name")]
+    pub game_name: String,
+    #[serde(rename = "game_tag")]
+    pub game_tag: String,
+    /// Chat ID, `{puuid}@{region}.pvp.net`.
+    #[serde(rename = "pid")]
+    pub pid: String,
+    #[serde(rename = "region", default)]
+    pub region: String,
+    #[serde(rename = "note", default)]
+    pub note: String,
+    #[serde(rename = "displayGroup", default)]
+    pub display_group: String,
+    /// Unix time in milliseconds; `None` while online.
+    #[serde(rename = "last_online_ts")]
+    pub last_online: Option<u64>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Friend {
+    /// `name#tag`.
+    pub fn riot_id(&self) -> String {
+        format!("{}#{}", self.game_name, self.game_tag)
+    }
+}
+
+/// Response of the local `chat/v4/friendrequests` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FriendRequests {
+    #[serde(rename = "requests", default)]
+    pub requests: Vec<FriendRequest>,
+}
+
+impl FriendRequests {
+    /// Requests waiting for the user to accept.
+    pub fn incoming(&self) -> impl Iterator<Item = &FriendRequest> {
+        self.requests.iter().filter(|r| r.direction == RequestDirection::Incoming)
+    }
+
+    /// Requests the user has sent.
+    pub fn outgoing(&self) -> impl Iterator<Item = &FriendRequest> {
+        self.requests.iter().filter(|r| r.direction == RequestDirection::Outgoing)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FriendRequest {
+    #[serde(rename = "puuid")]
+    pub puuid: String,
+    #[serde(rename = "game_name")]
+    pub game_name: String,
+    #[serde(rename = "game_tag")]
+    pub game_tag: String,
+    #[serde(rename = "pid")]
+    pub pid: String,
+    #[serde(rename = "region", default)]
+    pub region: String,
+    #[serde(rename = "note", default)]
+    pub note: String,
+    #[serde(rename = "subscription")]
+    pub direction: RequestDirection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RequestDirection {
+    #[serde(rename = "pending_in")]
+    Incoming,
+    #[serde(rename = "pending_out")]
+    Outgoing,
+    #[serde(other)]
+    Unknown,
+}
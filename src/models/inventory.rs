@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+/// Entitlement categories, decoded from their item type UUIDs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ItemType {
+    Agents,
+    /// Owned skins are listed per level; level 1 is the base skin.
+    SkinLevels,
+    SkinChromas,
+    Buddies,
+    Sprays,
+    PlayerCards,
+    PlayerTitles,
+    Contracts,
+    Other(String),
+}
+
+impl ItemType {
+    pub const AGENTS_ID: &'static str = "01bb38e1-da47-4e6a-9b3d-945fe4655707";
+    pub const SKIN_LEVELS_ID: &'static str = "e7c63390-eda7-46e0-bb7a-a6abdacd2433";
+    pub const SKIN_CHROMAS_ID: &'static str = "3ad1b2b2-acdb-4524-852f-954a76ddae0a";
+    pub const BUDDIES_ID: &'static str = "dd3bf334-87f3-40bd-b043-682a57a8dc3a";
+    pub const SPRAYS_ID: &'static str = "d5f120f8-ff8c-4aac-92ea-f2b5acbe9475";
+    pub const PLAYER_CARDS_ID: &'static str = "3f296c07-64c3-494c-923b-fe692a4fa1bd";
+    pub const PLAYER_TITLES_ID: &'static str = "de7caa6b-adf7-4588-bbd1-143831e786c6";
+    pub const CONTRACTS_ID: &'static str = "f85cb6f7-33e5-4dc8-b609-ec7212301948";
+
+    pub fn id(&self) -> &str {
+        match self {
+            ItemType::Agents => Self::AGENTS_ID,
+            ItemType::SkinLevels => Self::SKIN_LEVELS_ID,
+            ItemType::SkinChromas => Self::SKIN_CHROMAS_ID,
+            ItemType::Buddies => Self::BUDDIES_ID,
+            ItemType::Sprays => Self::SPRAYS_ID,
+            ItemType::PlayerCards => Self::PLAYER_CARDS_ID,
+            ItemType::PlayerTitles => Self::PLAYER_TITLES_ID,
+            ItemType::Contracts => Self::CONTRACTS_ID,
+            ItemType::Other(id) => id,
+        }
+    }
+}
+
+impl From<String> for ItemType {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            Self::AGENTS_ID => ItemType::Agents,
+            Self::SKIN_LEVELS_ID => ItemType::SkinLevels,
+            Self::SKIN_CHROMAS_ID => ItemType::SkinChromas,
+            Self::BUDDIES_ID => ItemType::Buddies,
+            Self::SPRAYS_ID => ItemType::Sprays,
+            Self::PLAYER_CARDS_ID => ItemType::PlayerCards,
+            Self::PLAYER_TITLES_ID => ItemType::PlayerTitles,
+            Self::CONTRACTS_ID => ItemType::Contracts,
+            _ => ItemType::Other(id),
+        }
+    }
+}
+
+impl From<ItemType> for String {
+    fn from(item_type: ItemType) -> Self {
+        item_type.id().to_string()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnedItems {
+    #[serde(rename = "ItemTypeID")]
+    pub item_type: ItemType,
+    #[serde(rename = "Entitlements", default)]
+    pub entitlements: Vec<Entitlement>,
+}
+
+impl OwnedItems {
+    pub fn contains(&self, item_id: &str) -> bool {
+        self.entitlements.iter().any(|e| e.item_id == item_id)
+    }
+
+    pub fn item_ids(&self) -> impl Iterator<Item = &str> {
+        self.entitlements.iter().map(|e| e.item_id.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entitlement {
+    #[serde(rename = "TypeID")]
+    pub type_id: String,
+    #[serde(rename = "ItemID")]
+    pub item_id: String,
+    /// Only set for buddies, which can be owned more than once.
+    #[serde(rename = "InstanceID", default, skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+}
+
+/// Everything the player owns. Free starter agents aren't entitlements
+/// and don't appear in `agents`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Inventory {
+    pub agents: OwnedItems,
+    pub skin_levels: OwnedItems,
+    pub skin_chromas: OwnedItems,
+    pub buddies: OwnedItems,
+    pub sprays: OwnedItems,
+    pub player_cards: OwnedItems,
+    pub player_titles: OwnedItems,
+    pub contracts: OwnedItems,
+}
+
+impl Inventory {
+    pub fn get(&self, item_type: &ItemType) -> Option<&OwnedItems> {
+        match item_type {
+            ItemType::Agents => Some(&self.agents),
+            ItemType::SkinLevels => Some(&self.skin_levels),
+            ItemType::SkinChromas => Some(&self.skin_chromas),
+            ItemType::Buddies => Some(&self.buddies),
+            ItemType::Sprays => Some(&self.sprays),
+            ItemType::PlayerCards => Some(&self.player_cards),
+            ItemType::PlayerTitles => Some(&self.player_titles),
+            ItemType::Contracts => Some(&self.contracts),
+            ItemType::Other(_) => None,
+        }
+    }
+
+    pub fn owns(&self, item_type: &ItemType, item_id: &str) -> bool {
+        self.get(item_type).is_some_and(|items| items.contains(item_id))
+    }
+}
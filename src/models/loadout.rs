@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Socket IDs used in coregame loadout items.
+pub mod sockets {
+    pub const SKIN: &str = "bcef87d6-209b-46c6-8b19-fbe40bd95abc";
+    pub const SKIN_LEVEL: &str = "e7c63390-eda7-46e0-bb7a-a6abdacd2433";
+    pub const CHROMA: &str = "3ad1b2b2-acdb-4524-852f-954a76ddae0a";
+    pub const BUDDY: &str = "77258665-71d1-4623-bc72-44db9bd5b3b3";
+    pub const BUDDY_LEVEL: &str = "dd3bf334-87f3-40bd-b043-682a57a8dc3a";
+}
+
+/// The player's equipped cosmetics, as read from and written to
+/// `personalization/v2/players/{puuid}/playerloadout`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerLoadout {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    #[serde(rename = "Version")]
+    pub version: u64,
+    #[serde(rename = "Guns")]
+    pub guns: Vec<GunLoadout>,
+    #[serde(rename = "Sprays")]
+    pub sprays: Vec<SprayLoadout>,
+    #[serde(rename = "Identity")]
+    pub identity: LoadoutIdentity,
+    #[serde(rename = "Incognito")]
+    pub incognito: bool,
+    /// Fields not modelled above, kept so `set_player_loadout` writes them back unchanged.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl PlayerLoadout {
+    pub fn gun(&self, weapon_id: &str) -> Option<&GunLoadout> {
+        self.guns.iter().find(|g| g.weapon_id == weapon_id)
+    }
+
+    pub fn gun_mut(&mut self, weapon_id: &str) -> Option<&mut GunLoadout> {
+        self.guns.iter_mut().find(|g| g.weapon_id == weapon_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GunLoadout {
+    #[serde(rename = "ID")]
+    pub weapon_id: String,
+    #[serde(rename = "SkinID")]
+    pub skin_id: String,
+    #[serde(rename = "SkinLevelID")]
+    pub skin_level_id: String,
+    #[serde(rename = "ChromaID")]
+    pub chroma_id: String,
+    #[serde(rename = "CharmInstanceID", skip_serializing_if = "Option::is_none")]
+    pub buddy_instance_id: Option<String>,
+    #[serde(rename = "CharmID", skip_serializing_if = "Option::is_none")]
+    pub buddy_id: Option<String>,
+    #[serde(rename = "CharmLevelID", skip_serializing_if = "Option::is_none")]
+    pub buddy_level_id: Option<String>,
+    #[serde(rename = "Attachments", default)]
+    pub attachments: Vec<serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SprayLoadout {
+    #[serde(rename = "EquipSlotID")]
+    pub equip_slot_id: String,
+    #[serde(rename = "SprayID")]
+    pub spray_id: String,
+    #[serde(rename = "SprayLevelID")]
+    pub spray_level_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadoutIdentity {
+    #[serde(rename = "PlayerCardID")]
+    pub player_card_id: String,
+    #[serde(rename = "PlayerTitleID")]
+    pub player_title_id: String,
+    #[serde(rename = "AccountLevel")]
+    pub account_level: u32,
+    #[serde(rename = "PreferredLevelBorderID")]
+    pub level_border_id: String,
+    #[serde(rename = "HideAccountLevel")]
+    pub hide_account_level: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoreGameLoadouts {
+    #[serde(rename = "Loadouts")]
+    pub loadouts: Vec<CoreGameLoadout>,
+}
+
+impl CoreGameLoadouts {
+    pub fn for_player(&self, puuid: &str) -> Option<&CoreGameLoadout> {
+        self.loadouts.iter().find(|l| l.loadout.puuid == puuid)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoreGameLoadout {
+    #[serde(rename = "CharacterID")]
+    pub character_id: String,
+    #[serde(rename = "Loadout")]
+    pub loadout: MatchLoadout,
+}
+
+/// A player's loadout as reported during a match, keyed by item sockets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchLoadout {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    #[serde(rename = "Sprays")]
+    pub sprays: MatchSprays,
+    /// Keyed by weapon ID.
+    #[serde(rename = "Items")]
+    pub items: HashMap<String, MatchLoadoutItem>,
+}
+
+impl MatchLoadout {
+    /// Flatten the socket layout into the same shape as `PlayerLoadout::guns`.
+    pub fn guns(&self) -> Vec<GunLoadout> {
+        self.items.values()
+            .map(|item| GunLoadout {
+                weapon_id: item.id.clone(),
+                skin_id: item.socket(sockets::SKIN).unwrap_or_default(),
+                skin_level_id: item.socket(sockets::SKIN_LEVEL).unwrap_or_default(),
+                chroma_id: item.socket(sockets::CHROMA).unwrap_or_default(),
+                buddy_instance_id: None,
+                buddy_id: item.socket(sockets::BUDDY),
+                buddy_level_id: item.socket(sockets::BUDDY_LEVEL),
+                attachments: Vec::new(),
+                extra: HashMap::new(),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchSprays {
+    #[serde(rename = "SpraySelections", default)]
+    pub selections: Vec<SpraySelection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpraySelection {
+    #[serde(rename = "SocketID")]
+    pub socket_id: String,
+    #[serde(rename = "SprayID")]
+    pub spray_id: String,
+    #[serde(rename = "LevelID")]
+    pub level_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchLoadoutItem {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "TypeID")]
+    pub type_id: String,
+    #[serde(rename = "Sockets")]
+    pub sockets: HashMap<String, LoadoutSocket>,
+}
+
+impl MatchLoadoutItem {
+    /// Item ID equipped in the given socket.
+    pub fn socket(&self, socket_id: &str) -> Option<String> {
+        self.sockets.get(socket_id).map(|s| s.item.id.clone())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadoutSocket {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "Item")]
+    pub item: SocketItem,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SocketItem {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "TypeID")]
+    pub type_id: String,
+}
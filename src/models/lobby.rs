@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::loadout::GunLoadout;
+use crate::models::player::CompetitiveTier;
+use crate::models::pregame::CharacterSelectionState;
+use crate::premade::Premades;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LobbyPhase {
+    /// Agent select; only the player's own team is visible.
+    Pregame,
+    InGame,
+}
+
+/// Every player in the current pregame or coregame match, enriched with
+/// names, ranks and loadouts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveLobby {
+    pub phase: LobbyPhase,
+    pub match_id: String,
+    pub map_id: String,
+    pub queue_id: String,
+    pub players: Vec<LobbyPlayer>,
+    pub premades: Premades,
+}
+
+impl LiveLobby {
+    pub fn player(&self, puuid: &str) -> Option<&LobbyPlayer> {
+        self.players.iter().find(|p| p.puuid == puuid)
+    }
+
+    pub fn allies(&self) -> impl Iterator<Item = &LobbyPlayer> {
+        self.players.iter().filter(|p| p.is_ally)
+    }
+
+    pub fn enemies(&self) -> impl Iterator<Item = &LobbyPlayer> {
+        self.players.iter().filter(|p| !p.is_ally)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LobbyPlayer {
+    pub puuid: String,
+    /// `None` for incognito players outside the user's own party.
+    pub name: Option<String>,
+    pub tag: Option<String>,
+    pub incognito: bool,
+    pub team_id: String,
+    /// On the user's team (includes the user).
+    pub is_ally: bool,
+    pub is_self: bool,
+    pub character_id: String,
+    /// Pregame only; `None` in game where every agent is locked.
+    pub selection_state: Option<CharacterSelectionState>,
+    /// `None` when the player hides their level.
+    pub account_level: Option<u32>,
+    pub current_tier: CompetitiveTier,
+    pub ranked_rating: u32,
+    pub leaderboard_rank: Option<u32>,
+    pub peak_tier: CompetitiveTier,
+    pub previous_act_tier: CompetitiveTier,
+    pub player_card_id: String,
+    pub player_title_id: String,
+    /// Equipped weapon skins; in game only.
+    pub skins: Vec<GunLoadout>,
+    pub party_id: Option<String>,
+    /// Index into `LiveLobby::premades`; `None` for solo players.
+    pub premade_group: Option<usize>,
+}
@@ -1,33 +1,150 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::models::player::CompetitiveTier;
+
+/// Riot sends `null` instead of `[]` for some lists (e.g. rounds in deathmatch).
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Post-match data from `match-details/v1/matches/{match_id}`.
+/// Fields this crate doesn't model yet land in `extra`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchDetails {
     #[serde(rename = "matchInfo")]
-    pub match_info: serde_json::Value,
+    pub match_info: MatchInfo,
     pub players: Vec<MatchPlayer>,
-    pub teams: Vec<serde_json::Value>,
-    #[serde(rename = "roundResults")]
-    pub round_results: Vec<serde_json::Value>,
-    pub kills: Vec<serde_json::Value>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub teams: Vec<Team>,
+    #[serde(rename = "roundResults", default, deserialize_with = "null_as_default")]
+    pub round_results: Vec<RoundResult>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub kills: Vec<Kill>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl MatchDetails {
+    pub fn player(&self, puuid: &str) -> Option<&MatchPlayer> {
+        self.players.iter().find(|p| p.subject == puuid)
+    }
+
+    pub fn team(&self, team_id: &str) -> Option<&Team> {
+        self.teams.iter().find(|t| t.team_id == team_id)
+    }
+
+    pub fn winning_team(&self) -> Option<&Team> {
+        self.teams.iter().find(|t| t.won)
+    }
+
+    pub fn kills_by<'a>(&'a self, puuid: &'a str) -> impl Iterator<Item = &'a Kill> {
+        self.kills.iter().filter(move |k| k.killer.as_deref() == Some(puuid))
+    }
+
+    pub fn deaths_of<'a>(&'a self, puuid: &'a str) -> impl Iterator<Item = &'a Kill> {
+        self.kills.iter().filter(move |k| k.victim == puuid)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchInfo {
+    #[serde(rename = "matchId")]
+    pub match_id: String,
+    /// Map asset path, e.g. `/Game/Maps/Ascent/Ascent`.
+    #[serde(rename = "mapId")]
+    pub map_id: String,
+    #[serde(rename = "gamePodId")]
+    pub game_pod_id: String,
+    #[serde(rename = "gameLoopZone")]
+    pub game_loop_zone: String,
+    #[serde(rename = "gameVersion")]
+    pub game_version: String,
+    /// `None` while the match is still being processed.
+    #[serde(rename = "gameLengthMillis")]
+    pub game_length_millis: Option<u64>,
+    #[serde(rename = "gameStartMillis")]
+    pub game_start_millis: i64,
+    /// `Matchmaking` or `CustomGame`.
+    #[serde(rename = "provisioningFlowID")]
+    pub provisioning_flow_id: String,
+    #[serde(rename = "isCompleted")]
+    pub is_completed: bool,
+    #[serde(rename = "customGameName", default)]
+    pub custom_game_name: String,
+    #[serde(rename = "queueID")]
+    pub queue: Queue,
+    #[serde(rename = "gameMode")]
+    pub game_mode: String,
+    #[serde(rename = "isRanked")]
+    pub is_ranked: bool,
+    #[serde(rename = "seasonId")]
+    pub season_id: String,
+    #[serde(rename = "completionState", default)]
+    pub completion_state: String,
+    #[serde(rename = "platformType", default)]
+    pub platform_type: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchPlayer {
     pub subject: String,
     #[serde(rename = "gameName")]
     pub game_name: String,
     #[serde(rename = "tagLine")]
     pub tag_line: String,
+    #[serde(rename = "platformInfo")]
+    pub platform_info: Option<PlatformInfo>,
     #[serde(rename = "teamId")]
     pub team_id: String,
+    #[serde(rename = "partyId")]
+    pub party_id: String,
     #[serde(rename = "characterId")]
     pub character_id: String,
     pub stats: PlayerStats,
+    #[serde(rename = "roundDamage", default, deserialize_with = "null_as_default")]
+    pub round_damage: Vec<RoundDamage>,
     #[serde(rename = "competitiveTier")]
-    pub competitive_tier: u32,
+    pub competitive_tier: CompetitiveTier,
+    #[serde(rename = "isObserver", default)]
+    pub is_observer: bool,
+    #[serde(rename = "playerCard")]
+    pub player_card: String,
+    #[serde(rename = "playerTitle")]
+    pub player_title: String,
+    #[serde(rename = "preferredLevelBorder", default)]
+    pub preferred_level_border: Option<String>,
+    #[serde(rename = "accountLevel")]
+    pub account_level: u32,
+    #[serde(rename = "sessionPlaytimeMinutes", default)]
+    pub session_playtime_minutes: Option<u32>,
+    #[serde(rename = "behaviorFactors", default)]
+    pub behavior_factors: Option<BehaviorFactors>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlatformInfo {
+    #[serde(rename = "platformType", default)]
+    pub platform_type: String,
+    #[serde(rename = "platformOS", default)]
+    pub platform_os: String,
+    #[serde(rename = "platformOSVersion", default)]
+    pub platform_os_version: String,
+    #[serde(rename = "platformChipset", default)]
+    pub platform_chipset: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStats {
     pub score: u32,
     #[serde(rename = "roundsPlayed")]
@@ -35,10 +152,307 @@ pub struct PlayerStats {
     pub kills: u32,
     pub deaths: u32,
     pub assists: u32,
-    #[serde(rename = "playtimeMillis")]
+    #[serde(rename = "playtimeMillis", default)]
     pub playtime_millis: u64,
-    #[serde(rename = "abilityCasts")]
-    pub ability_casts: Option<serde_json::Value>,
+    /// Missing in some modes, e.g. deathmatch.
+    #[serde(rename = "abilityCasts", default)]
+    pub ability_casts: Option<AbilityCasts>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbilityCasts {
+    #[serde(rename = "grenadeCasts", default)]
+    pub grenade: u32,
+    #[serde(rename = "ability1Casts", default)]
+    pub ability1: u32,
+    #[serde(rename = "ability2Casts", default)]
+    pub ability2: u32,
+    #[serde(rename = "ultimateCasts", default)]
+    pub ultimate: u32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Total damage dealt to one opponent in one round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundDamage {
+    pub round: u32,
+    pub receiver: String,
+    pub damage: u32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BehaviorFactors {
+    #[serde(rename = "afkRounds", default)]
+    pub afk_rounds: f64,
+    #[serde(rename = "collisions", default)]
+    pub collisions: f64,
+    #[serde(rename = "damageParticipationOutgoing", default)]
+    pub damage_participation_outgoing: f64,
+    #[serde(rename = "friendlyFireIncoming", default)]
+    pub friendly_fire_incoming: f64,
+    #[serde(rename = "friendlyFireOutgoing", default)]
+    pub friendly_fire_outgoing: f64,
+    #[serde(rename = "stayedInSpawnRounds", default)]
+    pub stayed_in_spawn_rounds: f64,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Team {
+    /// `Blue`/`Red`, or a player's PUUID in deathmatch.
+    #[serde(rename = "teamId")]
+    pub team_id: String,
+    pub won: bool,
+    #[serde(rename = "roundsPlayed")]
+    pub rounds_played: u32,
+    #[serde(rename = "roundsWon")]
+    pub rounds_won: u32,
+    /// Rounds won, or kills in deathmatch.
+    #[serde(rename = "numPoints")]
+    pub num_points: u32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundResult {
+    /// Zero-based.
+    #[serde(rename = "roundNum")]
+    pub round_num: u32,
+    /// e.g. `Eliminated`, `Bomb detonated`, `Bomb defused`, `Round timer expired`.
+    #[serde(rename = "roundResult")]
+    pub round_result: String,
+    #[serde(rename = "roundCeremony")]
+    pub round_ceremony: String,
+    #[serde(rename = "winningTeam")]
+    pub winning_team: String,
+    #[serde(rename = "winningTeamRole", default)]
+    pub winning_team_role: Option<String>,
+    #[serde(rename = "bombPlanter", default)]
+    pub bomb_planter: Option<String>,
+    #[serde(rename = "bombDefuser", default)]
+    pub bomb_defuser: Option<String>,
+    #[serde(rename = "plantRoundTime", default)]
+    pub plant_round_time: u64,
+    #[serde(rename = "plantPlayerLocations", default, deserialize_with = "null_as_default")]
+    pub plant_player_locations: Vec<PlayerLocation>,
+    #[serde(rename = "plantLocation", default)]
+    pub plant_location: Location,
+    #[serde(rename = "plantSite", default)]
+    pub plant_site: String,
+    #[serde(rename = "defuseRoundTime", default)]
+    pub defuse_round_time: u64,
+    #[serde(rename = "defusePlayerLocations", default, deserialize_with = "null_as_default")]
+    pub defuse_player_locations: Vec<PlayerLocation>,
+    #[serde(rename = "defuseLocation", default)]
+    pub defuse_location: Location,
+    #[serde(rename = "playerStats", default, deserialize_with = "null_as_default")]
+    pub player_stats: Vec<PlayerRoundStats>,
+    #[serde(rename = "roundResultCode")]
+    pub round_result_code: String,
+    #[serde(rename = "playerEconomies", default, deserialize_with = "null_as_default")]
+    pub player_economies: Vec<PlayerEconomy>,
+    #[serde(rename = "playerScores", default, deserialize_with = "null_as_default")]
+    pub player_scores: Vec<PlayerScore>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl RoundResult {
+    pub fn player_stats(&self, puuid: &str) -> Option<&PlayerRoundStats> {
+        self.player_stats.iter().find(|s| s.subject == puuid)
+    }
+
+    pub fn plant(&self) -> Option<BombEvent> {
+        self.bomb_planter.as_ref().map(|player| BombEvent {
+            player: player.clone(),
+            round_time: self.plant_round_time,
+            site: self.plant_site.clone(),
+            location: self.plant_location.clone(),
+            player_locations: self.plant_player_locations.clone(),
+        })
+    }
+
+    pub fn defuse(&self) -> Option<BombEvent> {
+        self.bomb_defuser.as_ref().map(|player| BombEvent {
+            player: player.clone(),
+            round_time: self.defuse_round_time,
+            site: self.plant_site.clone(),
+            location: self.defuse_location.clone(),
+            player_locations: self.defuse_player_locations.clone(),
+        })
+    }
+}
+
+/// A spike plant or defuse, assembled from the `plant*`/`defuse*` round fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BombEvent {
+    pub player: String,
+    /// Milliseconds into the round.
+    pub round_time: u64,
+    pub site: String,
+    pub location: Location,
+    /// Where everyone was standing at the time.
+    pub player_locations: Vec<PlayerLocation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerRoundStats {
+    pub subject: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub kills: Vec<Kill>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub damage: Vec<Damage>,
+    #[serde(default)]
+    pub score: u32,
+    /// Missing in modes without a buy phase, e.g. deathmatch.
+    #[serde(default)]
+    pub economy: Option<Economy>,
+    #[serde(default)]
+    pub ability: Option<AbilityEffects>,
+    #[serde(rename = "wasAfk", default)]
+    pub was_afk: bool,
+    #[serde(rename = "wasPenalized", default)]
+    pub was_penalized: bool,
+    #[serde(rename = "stayedInSpawn", default)]
+    pub stayed_in_spawn: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbilityEffects {
+    #[serde(rename = "grenadeEffects", default)]
+    pub grenade: Option<serde_json::Value>,
+    #[serde(rename = "ability1Effects", default)]
+    pub ability1: Option<serde_json::Value>,
+    #[serde(rename = "ability2Effects", default)]
+    pub ability2: Option<serde_json::Value>,
+    #[serde(rename = "ultimateEffects", default)]
+    pub ultimate: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Damage dealt to one opponent in a round, with hit locations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Damage {
+    pub receiver: String,
+    pub damage: u32,
+    #[serde(default)]
+    pub legshots: u32,
+    #[serde(default)]
+    pub bodyshots: u32,
+    #[serde(default)]
+    pub headshots: u32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Damage {
+    pub fn shots(&self) -> u32 {
+        self.headshots + self.bodyshots + self.legshots
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Kill {
+    /// Milliseconds since the match started.
+    #[serde(rename = "gameTime")]
+    pub game_time: u64,
+    /// Milliseconds since the round started.
+    #[serde(rename = "roundTime")]
+    pub round_time: u64,
+    /// Only set on the match-level `kills` list.
+    #[serde(default)]
+    pub round: Option<u32>,
+    /// `None` for deaths with no killer (fall damage, spike).
+    #[serde(default)]
+    pub killer: Option<String>,
+    pub victim: String,
+    #[serde(rename = "victimLocation")]
+    pub victim_location: Location,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub assistants: Vec<String>,
+    #[serde(rename = "playerLocations", default, deserialize_with = "null_as_default")]
+    pub player_locations: Vec<PlayerLocation>,
+    #[serde(rename = "finishingDamage")]
+    pub finishing_damage: FinishingDamage,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinishingDamage {
+    /// `Weapon`, `Ability`, `Bomb`, `Fall` or `Melee`.
+    #[serde(rename = "damageType", default)]
+    pub damage_type: String,
+    /// Weapon UUID, or ability slot (`Ability1`, `Ultimate`, ...).
+    #[serde(rename = "damageItem", default)]
+    pub damage_item: String,
+    #[serde(rename = "isSecondaryFireMode", default)]
+    pub is_secondary_fire_mode: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    pub x: i32,
+    pub y: i32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerLocation {
+    pub subject: String,
+    #[serde(rename = "viewRadians", default)]
+    pub view_radians: f64,
+    pub location: Location,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Buy-phase economy for one player in one round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Economy {
+    #[serde(rename = "loadoutValue", default)]
+    pub loadout_value: u32,
+    /// Weapon UUID; empty when nothing was bought.
+    #[serde(default)]
+    pub weapon: String,
+    #[serde(default)]
+    pub armor: String,
+    #[serde(default)]
+    pub remaining: u32,
+    #[serde(default)]
+    pub spent: u32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerEconomy {
+    pub subject: String,
+    /// Unknown fields end up in `economy.extra`.
+    #[serde(flatten)]
+    pub economy: Economy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerScore {
+    pub subject: String,
+    #[serde(default)]
+    pub score: u32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -48,7 +462,177 @@ pub struct MatchHistoryEntry {
     #[serde(rename = "GameStartTime")]
     pub game_start_time: i64,
     #[serde(rename = "QueueID")]
-    pub queue_id: String,
+    pub queue_id: Queue,
     #[serde(flatten)]
-    pub extra: std::collections::HashMap<String, serde_json::Value>,
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// One page of `match-history/v1/history/{puuid}`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MatchHistoryPage {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    #[serde(rename = "BeginIndex")]
+    pub begin_index: u32,
+    #[serde(rename = "EndIndex")]
+    pub end_index: u32,
+    /// Matches available across all pages for the requested queue.
+    #[serde(rename = "Total")]
+    pub total: u32,
+    #[serde(rename = "History", default)]
+    pub history: Vec<MatchHistoryEntry>,
+}
+
+/// Which slice of match history to fetch. Riot returns at most
+/// `MatchHistoryQuery::MAX_PAGE_SIZE` matches per request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchHistoryQuery {
+    pub start: u32,
+    pub end: u32,
+    /// `None` returns every queue.
+    pub queue: Option<Queue>,
+}
+
+impl MatchHistoryQuery {
+    pub const MAX_PAGE_SIZE: u32 = 20;
+
+    /// The newest `count` matches from any queue.
+    pub fn latest(count: u32) -> Self {
+        Self { start: 0, end: count, queue: None }
+    }
+
+    pub fn range(mut self, start: u32, end: u32) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    pub fn queue(mut self, queue: Queue) -> Self {
+        self.queue = Some(queue);
+        self
+    }
+
+    pub(crate) fn to_query_string(&self) -> String {
+        let mut query = format!("startIndex={}&endIndex={}", self.start, self.end);
+        if let Some(queue) = &self.queue {
+            query.push_str(&format!("&queue={}", queue.id()));
+        }
+        query
+    }
+}
+
+impl Default for MatchHistoryQuery {
+    fn default() -> Self {
+        Self::latest(Self::MAX_PAGE_SIZE)
+    }
+}
+
+/// Matchmaking queues, decoded from their queue IDs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Queue {
+    Competitive,
+    Unrated,
+    Swiftplay,
+    SpikeRush,
+    Deathmatch,
+    TeamDeathmatch,
+    Escalation,
+    Replication,
+    Premier,
+    /// Custom games have an empty queue ID.
+    Custom,
+    Other(String),
+}
+
+impl Queue {
+    pub fn id(&self) -> &str {
+        match self {
+            Queue::Competitive => "competitive",
+            Queue::Unrated => "unrated",
+            Queue::Swiftplay => "swiftplay",
+            Queue::SpikeRush => "spikerush",
+            Queue::Deathmatch => "deathmatch",
+            Queue::TeamDeathmatch => "hurm",
+            Queue::Escalation => "ggteam",
+            Queue::Replication => "onefa",
+            Queue::Premier => "premier",
+            Queue::Custom => "",
+            Queue::Other(id) => id,
+        }
+    }
+
+    /// e.g. `"Spike Rush"`; unknown queues fall back to their ID.
+    pub fn display_name(&self) -> &str {
+        match self {
+            Queue::Competitive => "Competitive",
+            Queue::Unrated => "Unrated",
+            Queue::Swiftplay => "Swiftplay",
+            Queue::SpikeRush => "Spike Rush",
+            Queue::Deathmatch => "Deathmatch",
+            Queue::TeamDeathmatch => "Team Deathmatch",
+            Queue::Escalation => "Escalation",
+            Queue::Replication => "Replication",
+            Queue::Premier => "Premier",
+            Queue::Custom => "Custom",
+            Queue::Other(id) => id,
+        }
+    }
+}
+
+impl std::fmt::Display for Queue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
+
+/// Map asset codenames and the names shown in game.
+const MAP_NAMES: &[(&str, &str)] = &[
+    ("Ascent", "Ascent"), ("Bonsai", "Split"), ("Canyon", "Fracture"), ("Duality", "Bind"),
+    ("Foxtrot", "Breeze"), ("Infinity", "Abyss"), ("Jam", "Lotus"), ("Juliett", "Sunset"),
+    ("Pitt", "Pearl"), ("Port", "Icebox"), ("Rook", "Corrode"), ("Triad", "Haven"),
+    ("Range", "The Range"), ("HURM_Alley", "District"), ("HURM_Bowl", "Kasbah"),
+    ("HURM_Helix", "Drift"), ("HURM_Yard", "Piazza"), ("HURM_HighTide", "Glitch"),
+];
+
+/// Display name for a map ID like `/Game/Maps/Bonsai/Bonsai` (→ `"Split"`).
+/// Unknown maps fall back to their codename.
+pub fn map_name(map_id: &str) -> &str {
+    let codename = map_id.trim_end_matches('/').rsplit('/').next().unwrap_or(map_id);
+    MAP_NAMES.iter()
+        .find(|(code, _)| *code == codename)
+        .map(|(_, name)| *name)
+        .unwrap_or(codename)
+}
+
+impl From<String> for Queue {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            "competitive" => Queue::Competitive,
+            "unrated" => Queue::Unrated,
+            "swiftplay" => Queue::Swiftplay,
+            "spikerush" => Queue::SpikeRush,
+            "deathmatch" => Queue::Deathmatch,
+            "hurm" => Queue::TeamDeathmatch,
+            "ggteam" => Queue::Escalation,
+            "onefa" => Queue::Replication,
+            "premier" => Queue::Premier,
+            "" => Queue::Custom,
+            _ => Queue::Other(id),
+        }
+    }
+}
+
+impl From<Queue> for String {
+    fn from(queue: Queue) -> Self {
+        queue.id().to_string()
+    }
+}
+
+impl std::str::FromStr for Queue {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Queue::from(s.to_string()))
+    }
 }
@@ -1,4 +1,15 @@
+pub mod account_xp;
+pub mod chat;
+pub mod content;
+pub mod contracts;
+pub mod friends;
+pub mod inventory;
+pub mod loadout;
+pub mod lobby;
 pub mod match_data;
+pub mod party;
 pub mod player;
 pub mod pregame;
+pub mod presence;
 pub mod session;
+pub mod store;
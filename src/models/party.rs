@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::player::{PlayerIdentity, SeasonalBadgeInfo};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyPlayer {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    #[serde(rename = "Version")]
+    pub version: u64,
+    #[serde(rename = "CurrentPartyID")]
+    pub party_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Party {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "Version")]
+    pub version: u64,
+    #[serde(rename = "Members")]
+    pub members: Vec<PartyMember>,
+    #[serde(rename = "State")]
+    pub state: PartyState,
+    #[serde(rename = "PreviousState")]
+    pub previous_state: PartyState,
+    #[serde(rename = "Accessibility")]
+    pub accessibility: Accessibility,
+    #[serde(rename = "MatchmakingData")]
+    pub matchmaking: MatchmakingData,
+    /// RFC 3339; `0001-01-01T00:00:00Z` when not queueing.
+    #[serde(rename = "QueueEntryTime")]
+    pub queue_entry_time: String,
+    #[serde(rename = "EligibleQueues", default)]
+    pub eligible_queues: Vec<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Party {
+    pub fn owner(&self) -> Option<&PartyMember> {
+        self.members.iter().find(|m| m.is_owner)
+    }
+
+    pub fn member(&self, puuid: &str) -> Option<&PartyMember> {
+        self.members.iter().find(|m| m.puuid == puuid)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyMember {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    #[serde(rename = "CompetitiveTier")]
+    pub competitive_tier: u32,
+    #[serde(rename = "PlayerIdentity")]
+    pub identity: PlayerIdentity,
+    #[serde(rename = "SeasonalBadgeInfo")]
+    pub seasonal_badge: Option<SeasonalBadgeInfo>,
+    #[serde(rename = "IsOwner", default)]
+    pub is_owner: bool,
+    #[serde(rename = "IsReady")]
+    pub is_ready: bool,
+    #[serde(rename = "Pings", default)]
+    pub pings: Vec<GamePodPing>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GamePodPing {
+    #[serde(rename = "Ping")]
+    pub ping: u32,
+    #[serde(rename = "GamePodID")]
+    pub game_pod_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchmakingData {
+    #[serde(rename = "QueueID")]
+    pub queue_id: String,
+    #[serde(rename = "PreferredGamePods", default)]
+    pub preferred_game_pods: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PartyState {
+    Default,
+    Matchmaking,
+    MatchmadeGameStarting,
+    CustomGameSetup,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Accessibility {
+    Open,
+    Closed,
+}
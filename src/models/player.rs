@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::content::Content;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct NameEntry {
@@ -12,7 +15,294 @@ pub struct MmrData {
     #[serde(rename = "Subject")]
     pub puuid: String,
     #[serde(rename = "LatestCompetitiveUpdate")]
-    pub latest_update: Option<serde_json::Value>,
+    pub latest_update: Option<CompetitiveUpdate>,
+    /// Keyed by queue ID (`competitive`, `unrated`, ...).
     #[serde(rename = "QueueSkills")]
-    pub queue_skills: serde_json::Value,
+    pub queue_skills: HashMap<String, QueueSkill>,
+    #[serde(rename = "IsLeaderboardAnonymized", default)]
+    pub is_leaderboard_anonymized: bool,
+    #[serde(rename = "IsActRankBadgeHidden", default)]
+    pub is_act_rank_badge_hidden: bool,
+}
+
+impl MmrData {
+    pub fn queue(&self, queue_id: &str) -> Option<&QueueSkill> {
+        self.queue_skills.get(queue_id)
+    }
+
+    /// Competitive stats for the act of the latest ranked game, which is not
+    /// necessarily the current act.
+    pub fn latest_ranked_season(&self) -> Option<&SeasonalInfo> {
+        let season_id = &self.latest_update.as_ref()?.season_id;
+        self.queue("competitive")?.season(season_id)
+    }
+
+    /// Tier after the latest ranked game, in whichever act that was.
+    pub fn latest_ranked_tier(&self) -> CompetitiveTier {
+        self.latest_ranked_season().map(|s| s.competitive_tier).unwrap_or(CompetitiveTier::Unranked)
+    }
+
+    /// RR in `season_id`; `0` if the player has no ranked games there.
+    pub fn ranked_rating(&self, season_id: &str) -> u32 {
+        self.queue("competitive")
+            .and_then(|q| q.season(season_id))
+            .map(|s| s.ranked_rating)
+            .unwrap_or(0)
+    }
+
+    /// Competitive tier at the end of `season_id`, or now for the current act.
+    pub fn season_tier(&self, season_id: &str) -> CompetitiveTier {
+        self.queue("competitive")
+            .and_then(|q| q.season(season_id))
+            .map(|s| s.competitive_tier)
+            .unwrap_or(CompetitiveTier::Unranked)
+    }
+
+    /// Highest tier the player has won a competitive game at, in any act.
+    /// `content` dates each act, so tiers from before Episode 5 (which had no
+    /// Ascendant) are renumbered first; acts missing from it count as current.
+    pub fn peak_tier(&self, content: &Content) -> CompetitiveTier {
+        let seasons = self.queue("competitive").and_then(|q| q.seasons.as_ref());
+        seasons.into_iter()
+            .flat_map(|s| s.values())
+            .flat_map(|s| {
+                let pre_episode_5 = content.seasons.iter()
+                    .find(|season| season.id == s.season_id)
+                    .is_some_and(|season| season.start_time.as_str() < EPISODE_5_START);
+                let wins = s.wins_by_tier.iter().flat_map(|w| w.keys()).filter_map(|t| t.parse::<u32>().ok());
+                wins.chain([u32::from(s.rank)])
+                    .map(move |tier| if pre_episode_5 { from_pre_episode_5(tier) } else { tier })
+            })
+            .max()
+            .map(CompetitiveTier::from)
+            .unwrap_or(CompetitiveTier::Unranked)
+    }
+
+    /// Leaderboard rank in `season_id`. `None` below Immortal or when the
+    /// player is off the leaderboard.
+    pub fn leaderboard_rank(&self, season_id: &str) -> Option<u32> {
+        self.queue("competitive")
+            .and_then(|q| q.season(season_id))
+            .map(|s| s.leaderboard_rank)
+            .filter(|&rank| rank > 0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueSkill {
+    #[serde(rename = "TotalGamesNeededForRating")]
+    pub total_games_needed_for_rating: u32,
+    #[serde(rename = "TotalGamesNeededForLeaderboard")]
+    pub total_games_needed_for_leaderboard: u32,
+    #[serde(rename = "CurrentSeasonGamesNeededForRating")]
+    pub current_season_games_needed_for_rating: u32,
+    /// `None` for queues the player has never played.
+    #[serde(rename = "SeasonalInfoBySeasonID")]
+    pub seasons: Option<HashMap<String, SeasonalInfo>>,
+}
+
+impl QueueSkill {
+    pub fn season(&self, season_id: &str) -> Option<&SeasonalInfo> {
+        self.seasons.as_ref()?.get(season_id)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonalInfo {
+    #[serde(rename = "SeasonID")]
+    pub season_id: String,
+    #[serde(rename = "NumberOfWins")]
+    pub number_of_wins: u32,
+    #[serde(rename = "NumberOfWinsWithPlacements")]
+    pub number_of_wins_with_placements: u32,
+    #[serde(rename = "NumberOfGames")]
+    pub number_of_games: u32,
+    /// Act rank (highest tier with enough wins), as shown on the badge.
+    #[serde(rename = "Rank")]
+    pub rank: CompetitiveTier,
+    #[serde(rename = "CapstoneWins")]
+    pub capstone_wins: u32,
+    #[serde(rename = "LeaderboardRank")]
+    pub leaderboard_rank: u32,
+    /// Tier at the end of the season, or now for the current one.
+    #[serde(rename = "CompetitiveTier")]
+    pub competitive_tier: CompetitiveTier,
+    #[serde(rename = "RankedRating")]
+    pub ranked_rating: u32,
+    /// Tier number → wins at that tier.
+    #[serde(rename = "WinsByTier")]
+    pub wins_by_tier: Option<HashMap<String, u32>>,
+    #[serde(rename = "GamesNeededForRating")]
+    pub games_needed_for_rating: u32,
+    #[serde(rename = "TotalWinsNeededForRank")]
+    pub total_wins_needed_for_rank: u32,
+}
+
+/// RR change from one competitive game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompetitiveUpdate {
+    #[serde(rename = "MatchID")]
+    pub match_id: String,
+    #[serde(rename = "MapID")]
+    pub map_id: String,
+    #[serde(rename = "SeasonID")]
+    pub season_id: String,
+    #[serde(rename = "MatchStartTime")]
+    pub match_start_time: i64,
+    #[serde(rename = "TierBeforeUpdate")]
+    pub tier_before: CompetitiveTier,
+    #[serde(rename = "TierAfterUpdate")]
+    pub tier_after: CompetitiveTier,
+    #[serde(rename = "RankedRatingBeforeUpdate")]
+    pub rr_before: u32,
+    #[serde(rename = "RankedRatingAfterUpdate")]
+    pub rr_after: u32,
+    #[serde(rename = "RankedRatingEarned")]
+    pub rr_earned: i32,
+    #[serde(rename = "RankedRatingPerformanceBonus")]
+    pub rr_performance_bonus: i32,
+    #[serde(rename = "CompetitiveMovement")]
+    pub movement: String,
+    #[serde(rename = "AFKPenalty")]
+    pub afk_penalty: i32,
+}
+
+impl CompetitiveUpdate {
+    pub fn promoted(&self) -> bool {
+        u32::from(self.tier_after) > u32::from(self.tier_before)
+    }
+
+    pub fn demoted(&self) -> bool {
+        u32::from(self.tier_after) < u32::from(self.tier_before)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompetitiveUpdates {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    /// Newest first.
+    #[serde(rename = "Matches", default)]
+    pub matches: Vec<CompetitiveUpdate>,
+}
+
+/// Competitive rank, decoded from Riot's tier number (0 = unranked, 3 = Iron 1, 27 = Radiant).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum CompetitiveTier {
+    #[default]
+    Unranked,
+    Iron1, Iron2, Iron3,
+    Bronze1, Bronze2, Bronze3,
+    Silver1, Silver2, Silver3,
+    Gold1, Gold2, Gold3,
+    Platinum1, Platinum2, Platinum3,
+    Diamond1, Diamond2, Diamond3,
+    Ascendant1, Ascendant2, Ascendant3,
+    Immortal1, Immortal2, Immortal3,
+    Radiant,
+    /// Tiers 1–2 (unused) or anything newer than this crate.
+    Unknown(u32),
+}
+
+/// Episode 5 added Ascendant as tiers 21-23, moving Immortal and Radiant up.
+const EPISODE_5_START: &str = "2022-06-22";
+
+/// Map a tier number from before Episode 5 (Immortal 21-23, Radiant 24) onto today's table.
+fn from_pre_episode_5(tier: u32) -> u32 {
+    match tier {
+        21..=23 => tier + 3,
+        24.. => 27,
+        tier => tier,
+    }
+}
+
+/// Ranked tiers in order, starting at tier 3.
+const RANKED_TIERS: [(CompetitiveTier, &str); 25] = [
+    (CompetitiveTier::Iron1, "Iron 1"), (CompetitiveTier::Iron2, "Iron 2"), (CompetitiveTier::Iron3, "Iron 3"),
+    (CompetitiveTier::Bronze1, "Bronze 1"), (CompetitiveTier::Bronze2, "Bronze 2"), (CompetitiveTier::Bronze3, "Bronze 3"),
+    (CompetitiveTier::Silver1, "Silver 1"), (CompetitiveTier::Silver2, "Silver 2"), (CompetitiveTier::Silver3, "Silver 3"),
+    (CompetitiveTier::Gold1, "Gold 1"), (CompetitiveTier::Gold2, "Gold 2"), (CompetitiveTier::Gold3, "Gold 3"),
+    (CompetitiveTier::Platinum1, "Platinum 1"), (CompetitiveTier::Platinum2, "Platinum 2"), (CompetitiveTier::Platinum3, "Platinum 3"),
+    (CompetitiveTier::Diamond1, "Diamond 1"), (CompetitiveTier::Diamond2, "Diamond 2"), (CompetitiveTier::Diamond3, "Diamond 3"),
+    (CompetitiveTier::Ascendant1, "Ascendant 1"), (CompetitiveTier::Ascendant2, "Ascendant 2"), (CompetitiveTier::Ascendant3, "Ascendant 3"),
+    (CompetitiveTier::Immortal1, "Immortal 1"), (CompetitiveTier::Immortal2, "Immortal 2"), (CompetitiveTier::Immortal3, "Immortal 3"),
+    (CompetitiveTier::Radiant, "Radiant"),
+];
+const FIRST_RANKED_TIER: u32 = 3;
+
+impl CompetitiveTier {
+    /// e.g. `"Gold 2"`.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CompetitiveTier::Unranked => "Unranked",
+            CompetitiveTier::Unknown(_) => "Unknown",
+            tier => RANKED_TIERS.iter().find(|(t, _)| t == tier).map(|(_, name)| *name).unwrap_or("Unknown"),
+        }
+    }
+
+    pub fn is_ranked(&self) -> bool {
+        !matches!(self, CompetitiveTier::Unranked | CompetitiveTier::Unknown(_))
+    }
+}
+
+impl std::fmt::Display for CompetitiveTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
+
+impl From<u32> for CompetitiveTier {
+    fn from(tier: u32) -> Self {
+        match tier {
+            0 => CompetitiveTier::Unranked,
+            t if t >= FIRST_RANKED_TIER && t < FIRST_RANKED_TIER + RANKED_TIERS.len() as u32 => {
+                RANKED_TIERS[(t - FIRST_RANKED_TIER) as usize].0
+            }
+            t => CompetitiveTier::Unknown(t),
+        }
+    }
+}
+
+impl From<CompetitiveTier> for u32 {
+    fn from(tier: CompetitiveTier) -> Self {
+        match tier {
+            CompetitiveTier::Unranked => 0,
+            CompetitiveTier::Unknown(t) => t,
+            tier => RANKED_TIERS.iter().position(|(t, _)| *t == tier)
+                .map(|i| i as u32 + FIRST_RANKED_TIER)
+                .unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerIdentity {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    #[serde(rename = "PlayerCardID")]
+    pub player_card_id: String,
+    #[serde(rename = "PlayerTitleID")]
+    pub player_title_id: String,
+    #[serde(rename = "AccountLevel")]
+    pub account_level: u32,
+    #[serde(rename = "PreferredLevelBorderID")]
+    pub level_border_id: String,
+    /// Player has "hide my name" enabled (shown as agent name in game).
+    #[serde(rename = "Incognito")]
+    pub incognito: bool,
+    #[serde(rename = "HideAccountLevel")]
+    pub hide_account_level: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonalBadgeInfo {
+    #[serde(rename = "SeasonID")]
+    pub season_id: String,
+    #[serde(rename = "NumberOfWins")]
+    pub number_of_wins: u32,
+    #[serde(rename = "Rank")]
+    pub rank: u32,
+    #[serde(rename = "LeaderboardRank")]
+    pub leaderboard_rank: u32,
 }
@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::models::player::{PlayerIdentity, SeasonalBadgeInfo};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PreGamePlayer {
@@ -8,10 +11,113 @@ pub struct PreGamePlayer {
     pub match_id: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PreGameMatch {
     #[serde(rename = "ID")]
     pub id: String,
+    #[serde(rename = "Version")]
+    pub version: u64,
+    #[serde(rename = "Teams", default)]
+    pub teams: Vec<PreGameTeam>,
+    /// The local player's team; `None` for observers.
+    #[serde(rename = "AllyTeam")]
+    pub ally_team: Option<PreGameTeam>,
+    /// Only populated in custom games — matchmaking hides the enemy team.
+    #[serde(rename = "EnemyTeam")]
+    pub enemy_team: Option<PreGameTeam>,
+    #[serde(rename = "ObserverSubjects", default)]
+    pub observers: Vec<String>,
+    #[serde(rename = "EnemyTeamSize")]
+    pub enemy_team_size: u32,
+    #[serde(rename = "EnemyTeamLockCount")]
+    pub enemy_team_lock_count: u32,
+    #[serde(rename = "PregameState")]
+    pub state: PregameState,
+    #[serde(rename = "LastUpdated")]
+    pub last_updated: String,
+    #[serde(rename = "MapID")]
+    pub map_id: String,
+    #[serde(rename = "Mode")]
+    pub mode_id: String,
+    #[serde(rename = "QueueID")]
+    pub queue_id: String,
+    #[serde(rename = "ProvisioningFlowID")]
+    pub provisioning_flow: String,
+    #[serde(rename = "IsRanked")]
+    pub is_ranked: bool,
+    #[serde(rename = "PhaseTimeRemainingNS")]
+    pub phase_time_remaining_ns: u64,
+    #[serde(rename = "StepTimeRemainingNS")]
+    pub step_time_remaining_ns: u64,
     #[serde(flatten)]
-    pub data: std::collections::HashMap<String, serde_json::Value>,
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl PreGameMatch {
+    /// Time left in the agent-select phase.
+    pub fn phase_time_remaining(&self) -> Duration {
+        Duration::from_nanos(self.phase_time_remaining_ns)
+    }
+
+    /// Find a player on the ally (or, in customs, enemy) team.
+    pub fn player(&self, puuid: &str) -> Option<&PreGameMatchPlayer> {
+        self.ally_team.iter()
+            .chain(self.enemy_team.iter())
+            .flat_map(|team| team.players.iter())
+            .find(|p| p.puuid == puuid)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreGameTeam {
+    #[serde(rename = "TeamID")]
+    pub team_id: String,
+    #[serde(rename = "Players")]
+    pub players: Vec<PreGameMatchPlayer>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreGameMatchPlayer {
+    #[serde(rename = "Subject")]
+    pub puuid: String,
+    /// Empty until the player hovers an agent.
+    #[serde(rename = "CharacterID")]
+    pub character_id: String,
+    /// Shared by players who queued together; solo players have their own.
+    #[serde(rename = "PartyID", default)]
+    pub party_id: String,
+    #[serde(rename = "CharacterSelectionState")]
+    pub selection_state: CharacterSelectionState,
+    #[serde(rename = "PregamePlayerState")]
+    pub player_state: String,
+    #[serde(rename = "CompetitiveTier")]
+    pub competitive_tier: u32,
+    #[serde(rename = "PlayerIdentity")]
+    pub identity: PlayerIdentity,
+    #[serde(rename = "SeasonalBadgeInfo")]
+    pub seasonal_badge: Option<SeasonalBadgeInfo>,
+    #[serde(rename = "IsCaptain")]
+    pub is_captain: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CharacterSelectionState {
+    #[serde(rename = "")]
+    None,
+    #[serde(rename = "selected")]
+    Selected,
+    #[serde(rename = "locked")]
+    Locked,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PregameState {
+    CharacterSelectActive,
+    CharacterSelectFinished,
+    Provisioned,
+    #[serde(other)]
+    Unknown,
 }
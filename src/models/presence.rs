@@ -0,0 +1,221 @@
+use base64::Engine;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+use crate::models::match_data::{map_name, Queue};
+use crate::models::party::{Accessibility, PartyState};
+use crate::models::player::CompetitiveTier;
+
+/// Response of the local `chat/v4/presences` endpoint: the user and every online friend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Presences {
+    #[serde(rename = "presences", default)]
+    pub presences: Vec<Presence>,
+}
+
+impl Presences {
+    pub fn get(&self, puuid: &str) -> Option<&Presence> {
+        self.presences.iter().find(|p| p.puuid == puuid)
+    }
+
+    /// Presences of players currently in Valorant, with the game state decoded.
+    pub fn valorant(&self) -> impl Iterator<Item = (&Presence, &ValorantPresence)> {
+        self.presences.iter().filter_map(|p| p.valorant.as_ref().map(|v| (p, v)))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Presence {
+    #[serde(rename = "puuid")]
+    pub puuid: String,
+    #[serde(rename = "game_name")]
+    pub game_name: String,
+    #[serde(rename = "game_tag")]
+    pub game_tag: String,
+    /// Chat status: `chat`, `away`, `dnd`, `mobile`, ...
+    #[serde(rename = "state")]
+    pub state: String,
+    /// `valorant`, `league_of_legends`, ...
+    #[serde(rename = "product")]
+    pub product: String,
+    #[serde(rename = "pid")]
+    pub pid: String,
+    #[serde(rename = "region", default)]
+    pub region: String,
+    /// Unix time in milliseconds.
+    #[serde(rename = "time", default)]
+    pub time: u64,
+    /// The base64 `private` blob, decoded. `None` for other products or
+    /// when the blob can't be decoded.
+    #[serde(rename(serialize = "valorant", deserialize = "private"), default, deserialize_with = "decode_private")]
+    pub valorant: Option<ValorantPresence>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Presence {
+    /// `name#tag`.
+    pub fn riot_id(&self) -> String {
+        format!("{}#{}", self.game_name, self.game_tag)
+    }
+}
+
+fn decode_private<'de, D>(deserializer: D) -> Result<Option<ValorantPresence>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let encoded: Option<String> = Option::deserialize(deserializer)?;
+    Ok(encoded
+        .and_then(|blob| base64::engine::general_purpose::STANDARD.decode(blob).ok())
+        .and_then(|json| serde_json::from_slice(&json).ok()))
+}
+
+/// Valorant's private presence payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValorantPresence {
+    #[serde(rename = "isValid", default)]
+    pub is_valid: bool,
+    #[serde(rename = "isIdle", default)]
+    pub is_idle: bool,
+    #[serde(rename = "matchPresenceData")]
+    pub match_data: MatchPresence,
+    #[serde(rename = "partyPresenceData")]
+    pub party: PartyPresence,
+    #[serde(rename = "playerPresenceData")]
+    pub player: PlayerPresence,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl ValorantPresence {
+    pub fn session_state(&self) -> SessionLoopState {
+        self.match_data.session_loop_state
+    }
+
+    pub fn queue(&self) -> &Queue {
+        &self.match_data.queue_id
+    }
+
+    pub fn is_queueing(&self) -> bool {
+        self.party.party_state == PartyState::Matchmaking
+    }
+
+    /// Map ID of the current match, or `None` in the menus.
+    pub fn map_id(&self) -> Option<&str> {
+        Some(self.match_data.match_map.as_str()).filter(|m| !m.is_empty())
+    }
+
+    /// e.g. `"Ascent"`.
+    pub fn map_name(&self) -> Option<&str> {
+        self.map_id().map(map_name)
+    }
+
+    /// `(own team, enemy team)` rounds while in a match.
+    pub fn score(&self) -> Option<(u32, u32)> {
+        (self.session_state() == SessionLoopState::InGame)
+            .then_some((self.party.score_ally_team, self.party.score_enemy_team))
+    }
+
+    pub fn party_size(&self) -> u32 {
+        self.party.party_size
+    }
+
+    pub fn competitive_tier(&self) -> CompetitiveTier {
+        self.player.competitive_tier
+    }
+
+    pub fn account_level(&self) -> u32 {
+        self.player.account_level
+    }
+
+    /// A one-line description, e.g. `"In a Competitive match on Ascent, 7-5"`.
+    pub fn summary(&self) -> String {
+        let queue = self.queue();
+        match self.session_state() {
+            SessionLoopState::InGame => {
+                let map = self.map_name().unwrap_or("an unknown map");
+                match self.score() {
+                    Some((ally, enemy)) => format!("In a {} match on {}, {}-{}", queue, map, ally, enemy),
+                    None => format!("In a {} match on {}", queue, map),
+                }
+            }
+            SessionLoopState::Pregame => format!("In agent select ({})", queue),
+            SessionLoopState::Menus if self.is_queueing() => format!("Queueing for {}", queue),
+            SessionLoopState::Menus if self.is_idle => "Away".to_string(),
+            _ => "In the menus".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionLoopState {
+    #[serde(rename = "MENUS")]
+    Menus,
+    #[serde(rename = "PREGAME")]
+    Pregame,
+    #[serde(rename = "INGAME")]
+    InGame,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchPresence {
+    #[serde(rename = "sessionLoopState")]
+    pub session_loop_state: SessionLoopState,
+    #[serde(rename = "provisioningFlow", default)]
+    pub provisioning_flow: String,
+    /// Empty in the menus.
+    #[serde(rename = "matchMap", default)]
+    pub match_map: String,
+    #[serde(rename = "queueId", default = "custom_queue")]
+    pub queue_id: Queue,
+}
+
+fn custom_queue() -> Queue {
+    Queue::Custom
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyPresence {
+    #[serde(rename = "partyId", default)]
+    pub party_id: String,
+    #[serde(rename = "isPartyOwner", default)]
+    pub is_party_owner: bool,
+    #[serde(rename = "partyState")]
+    pub party_state: PartyState,
+    #[serde(rename = "partyAccessibility")]
+    pub accessibility: Accessibility,
+    #[serde(rename = "partySize", default)]
+    pub party_size: u32,
+    #[serde(rename = "maxPartySize", default)]
+    pub max_party_size: u32,
+    #[serde(rename = "partyOwnerMatchCurrentTeam", default)]
+    pub current_team: String,
+    /// Rounds won by the player's team in the current match.
+    #[serde(rename = "partyOwnerMatchScoreAllyTeam", default)]
+    pub score_ally_team: u32,
+    #[serde(rename = "partyOwnerMatchScoreEnemyTeam", default)]
+    pub score_enemy_team: u32,
+    #[serde(rename = "customGameName", default)]
+    pub custom_game_name: String,
+    #[serde(rename = "queueEntryTime", default)]
+    pub queue_entry_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerPresence {
+    #[serde(rename = "playerCardId", default)]
+    pub player_card_id: String,
+    #[serde(rename = "playerTitleId", default)]
+    pub player_title_id: String,
+    #[serde(rename = "preferredLevelBorderId", default)]
+    pub preferred_level_border_id: String,
+    #[serde(rename = "accountLevel", default)]
+    pub account_level: u32,
+    #[serde(rename = "competitiveTier", default)]
+    pub competitive_tier: CompetitiveTier,
+    /// `0` when not on the leaderboard.
+    #[serde(rename = "leaderboardPosition", default)]
+    pub leaderboard_position: u32,
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::player::{PlayerIdentity, SeasonalBadgeInfo};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CoreGamePlayer {
     #[serde(rename = "MatchID")]
@@ -18,10 +20,20 @@ pub struct CoreGameMatch {
     pub mode_id: String,
     #[serde(rename = "Players")]
     pub players: Vec<CoreGameMatchPlayer>,
-    #[serde(rename = "Teams")]
+    #[serde(rename = "Teams", default)]
     pub teams: Vec<serde_json::Value>,
     #[serde(rename = "RoundResults")]
     pub round_results: Option<Vec<serde_json::Value>>,
+    #[serde(rename = "MatchmakingData")]
+    pub matchmaking: Option<CoreGameMatchmaking>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CoreGameMatchmaking {
+    #[serde(rename = "QueueID")]
+    pub queue_id: String,
+    #[serde(rename = "IsRanked")]
+    pub is_ranked: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,10 +44,13 @@ pub struct CoreGameMatchPlayer {
     pub team_id: String,
     #[serde(rename = "CharacterID")]
     pub character_id: String,
+    /// Shared by players who queued together; solo players have their own.
+    #[serde(rename = "PartyID", default)]
+    pub party_id: String,
     #[serde(rename = "PlayerIdentity")]
-    pub identity: serde_json::Value,
+    pub identity: PlayerIdentity,
     #[serde(rename = "SeasonalBadgeInfo")]
-    pub seasonal_badge: Option<serde_json::Value>,
+    pub seasonal_badge: Option<SeasonalBadgeInfo>,
     #[serde(rename = "IsCoach")]
     pub is_coach: bool,
     #[serde(rename = "IsAssociated")]
@@ -47,3 +62,11 @@ pub struct SessionData {
     #[serde(flatten)]
     pub data: serde_json::Value,
 }
+
+impl SessionData {
+    /// Whether the Riot Client reports a Valorant session.
+    pub fn valorant_running(&self) -> bool {
+        self.data.as_object()
+            .is_some_and(|sessions| sessions.values().any(|s| s["productId"] == "valorant"))
+    }
+}
@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+/// In-game currencies, decoded from their UUIDs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Currency {
+    ValorantPoints,
+    Radianite,
+    KingdomCredits,
+    FreeAgents,
+    Other(String),
+}
+
+impl Currency {
+    pub const VALORANT_POINTS_ID: &'static str = "85ad13f7-3d1b-5128-9eb2-7cd8ee0b5741";
+    pub const RADIANITE_ID: &'static str = "e59aa87c-4cbf-517a-5983-6e81511be9b7";
+    pub const KINGDOM_CREDITS_ID: &'static str = "85ca954a-41f2-ce94-9b45-8ca3dd39a00d";
+    pub const FREE_AGENTS_ID: &'static str = "f08d4ae3-939c-4576-ab26-09ce1f23bb37";
+
+    pub fn id(&self) -> &str {
+        match self {
+            Currency::ValorantPoints => Self::VALORANT_POINTS_ID,
+            Currency::Radianite => Self::RADIANITE_ID,
+            Currency::KingdomCredits => Self::KINGDOM_CREDITS_ID,
+            Currency::FreeAgents => Self::FREE_AGENTS_ID,
+            Currency::Other(id) => id,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Currency::ValorantPoints => "VP",
+            Currency::Radianite => "Radianite",
+            Currency::KingdomCredits => "Kingdom Credits",
+            Currency::FreeAgents => "Free Agents",
+            Currency::Other(id) => id,
+        }
+    }
+}
+
+impl From<String> for Currency {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            Self::VALORANT_POINTS_ID => Currency::ValorantPoints,
+            Self::RADIANITE_ID => Currency::Radianite,
+            Self::KINGDOM_CREDITS_ID => Currency::KingdomCredits,
+            Self::FREE_AGENTS_ID => Currency::FreeAgents,
+            _ => Currency::Other(id),
+        }
+    }
+}
+
+impl From<Currency> for String {
+    fn from(currency: Currency) -> Self {
+        currency.id().to_string()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Wallet {
+    #[serde(rename = "Balances")]
+    pub balances: HashMap<Currency, u64>,
+}
+
+impl Wallet {
+    pub fn balance(&self, currency: &Currency) -> u64 {
+        self.balances.get(currency).copied().unwrap_or(0)
+    }
+
+    pub fn valorant_points(&self) -> u64 {
+        self.balance(&Currency::ValorantPoints)
+    }
+
+    pub fn radianite(&self) -> u64 {
+        self.balance(&Currency::Radianite)
+    }
+
+    pub fn kingdom_credits(&self) -> u64 {
+        self.balance(&Currency::KingdomCredits)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Offer {
+    #[serde(rename = "OfferID")]
+    pub offer_id: String,
+    #[serde(rename = "IsDirectPurchase")]
+    pub is_direct_purchase: bool,
+    #[serde(rename = "StartDate")]
+    pub start_date: String,
+    #[serde(rename = "Cost")]
+    pub cost: HashMap<Currency, u32>,
+    #[serde(rename = "Rewards")]
+    pub rewards: Vec<OfferReward>,
+}
+
+impl Offer {
+    /// Price in the offer's (single) currency.
+    pub fn price(&self) -> Option<(&Currency, u32)> {
+        self.cost.iter().next().map(|(c, amount)| (c, *amount))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfferReward {
+    #[serde(rename = "ItemTypeID")]
+    pub item_type_id: String,
+    #[serde(rename = "ItemID")]
+    pub item_id: String,
+    #[serde(rename = "Quantity")]
+    pub quantity: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoreOffers {
+    #[serde(rename = "Offers")]
+    pub offers: Vec<Offer>,
+}
+
+impl StoreOffers {
+    pub fn find(&self, item_id: &str) -> Option<&Offer> {
+        self.offers.iter().find(|o| o.rewards.iter().any(|r| r.item_id == item_id))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Storefront {
+    #[serde(rename = "FeaturedBundle")]
+    pub featured_bundle: FeaturedBundle,
+    #[serde(rename = "SkinsPanelLayout")]
+    pub skins_panel: SkinsPanel,
+    /// Night market; absent when it isn't running.
+    #[serde(rename = "BonusStore")]
+    pub bonus_store: Option<BonusStore>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+    /// When this response was decoded; the `*RemainingDurationInSeconds`
+    /// fields count down from here.
+    #[serde(skip, default = "SystemTime::now")]
+    pub fetched_at: SystemTime,
+}
+
+impl Storefront {
+    /// Time until the daily skin offers rotate.
+    pub fn daily_offers_remaining(&self) -> Duration {
+        self.remaining(self.skins_panel.remaining_seconds)
+    }
+
+    /// Time until the featured bundle rotates.
+    pub fn bundle_remaining(&self) -> Duration {
+        self.remaining(self.featured_bundle.remaining_seconds)
+    }
+
+    pub fn night_market_remaining(&self) -> Option<Duration> {
+        self.bonus_store.as_ref().map(|b| self.remaining(b.remaining_seconds))
+    }
+
+    fn remaining(&self, seconds: u64) -> Duration {
+        let elapsed = self.fetched_at.elapsed().unwrap_or_default();
+        Duration::from_secs(seconds).saturating_sub(elapsed)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeaturedBundle {
+    #[serde(rename = "Bundles")]
+    pub bundles: Vec<Bundle>,
+    #[serde(rename = "BundleRemainingDurationInSeconds")]
+    pub remaining_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bundle {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "DataAssetID")]
+    pub data_asset_id: String,
+    #[serde(rename = "CurrencyID")]
+    pub currency: Currency,
+    #[serde(rename = "Items")]
+    pub items: Vec<BundleItem>,
+    #[serde(rename = "DurationRemainingInSeconds")]
+    pub remaining_seconds: u64,
+    #[serde(rename = "WholesaleOnly")]
+    pub wholesale_only: bool,
+}
+
+impl Bundle {
+    /// Sum of discounted item prices.
+    pub fn total_price(&self) -> u32 {
+        self.items.iter().map(|i| i.discounted_price).sum()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleItem {
+    #[serde(rename = "Item")]
+    pub item: BundleItemRef,
+    #[serde(rename = "BasePrice")]
+    pub base_price: u32,
+    #[serde(rename = "CurrencyID")]
+    pub currency: Currency,
+    #[serde(rename = "DiscountPercent")]
+    pub discount_percent: f64,
+    #[serde(rename = "DiscountedPrice")]
+    pub discounted_price: u32,
+    #[serde(rename = "IsPromoItem")]
+    pub is_promo_item: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleItemRef {
+    #[serde(rename = "ItemTypeID")]
+    pub item_type_id: String,
+    #[serde(rename = "ItemID")]
+    pub item_id: String,
+    #[serde(rename = "Amount")]
+    pub amount: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkinsPanel {
+    /// Skin level IDs of today's four offers.
+    #[serde(rename = "SingleItemOffers")]
+    pub offer_ids: Vec<String>,
+    #[serde(rename = "SingleItemStoreOffers", default)]
+    pub offers: Vec<Offer>,
+    #[serde(rename = "SingleItemOffersRemainingDurationInSeconds")]
+    pub remaining_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BonusStore {
+    #[serde(rename = "BonusStoreOffers")]
+    pub offers: Vec<BonusOffer>,
+    #[serde(rename = "BonusStoreRemainingDurationInSeconds")]
+    pub remaining_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BonusOffer {
+    #[serde(rename = "BonusOfferID")]
+    pub bonus_offer_id: String,
+    #[serde(rename = "Offer")]
+    pub offer: Offer,
+    #[serde(rename = "DiscountPercent")]
+    pub discount_percent: u32,
+    #[serde(rename = "DiscountCosts")]
+    pub discount_costs: HashMap<Currency, u32>,
+    #[serde(rename = "IsSeen")]
+    pub is_seen: bool,
+}
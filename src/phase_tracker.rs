@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, RwLock};
+
+use crate::client::ValorantClient;
+use crate::error::ValorantError;
+use crate::lockfile_watcher::ConnectionState;
+use crate::log_watcher::LogEvent;
+use crate::models::party::PartyState;
+use crate::models::presence::SessionLoopState;
+
+/// How often the phase is polled when no log events arrive.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// How long the post-game screen is assumed to stay up before falling back to `Menu`.
+const POST_GAME_HOLD: Duration = Duration::from_secs(20);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GamePhase {
+    /// Riot Client or Valorant is not running.
+    NotRunning,
+    Menu,
+    Queueing,
+    /// Agent select.
+    Pregame,
+    InGame,
+    /// The match just ended.
+    PostGame,
+}
+
+/// A phase and the match it belongs to, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseState {
+    pub phase: GamePhase,
+    /// Set in `Pregame`, `InGame` and `PostGame`.
+    pub match_id: Option<String>,
+}
+
+impl PhaseState {
+    fn new(phase: GamePhase, match_id: Option<String>) -> Self {
+        Self { phase, match_id }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseChange {
+    pub previous: GamePhase,
+    pub phase: GamePhase,
+    pub match_id: Option<String>,
+}
+
+impl ValorantClient {
+    /// Work out the current phase from the local session and the user's own
+    /// presence, then GLZ. While presence says the user is in the menus, GLZ
+    /// is not polled at all; without presence the GLZ party state decides
+    /// between `Menu` and `Queueing`. `PostGame` is never returned here; it
+    /// needs history, see `PhaseTracker`.
+    pub async fn game_phase(&self) -> Result<PhaseState, ValorantError> {
+        if self.connection_state().await != ConnectionState::Connected {
+            return Ok(PhaseState::new(GamePhase::NotRunning, None));
+        }
+        match self.session().await {
+            Ok(session) if session.valorant_running() => {}
+            _ => return Ok(PhaseState::new(GamePhase::NotRunning, None)),
+        }
+
+        let puuid = self.puuid().await;
+        let presence = self.presences().await.ok()
+            .and_then(|presences| presences.get(&puuid).and_then(|p| p.valorant.clone()));
+        if let Some(presence) = presence.as_ref().filter(|p| p.session_state() == SessionLoopState::Menus) {
+            return Ok(PhaseState::new(menu_phase(Some(presence.party.party_state)), None));
+        }
+
+        match self.pregame_player(&puuid).await {
+            Ok(player) => return Ok(PhaseState::new(GamePhase::Pregame, Some(player.match_id))),
+            Err(ValorantError::NotInMatch) => {}
+            Err(e) => return Err(e),
+        }
+        match self.coregame_player(&puuid).await {
+            Ok(player) => return Ok(PhaseState::new(GamePhase::InGame, Some(player.match_id))),
+            Err(ValorantError::NotInMatch) => {}
+            Err(e) => return Err(e),
+        }
+
+        // Presence can lag behind the end of a match; the party state is still current.
+        let party_state = match presence {
+            Some(presence) => Some(presence.party.party_state),
+            None => match self.party_player(&puuid).await {
+                Ok(player) => self.party(&player.party_id).await.ok().map(|p| p.state),
+                Err(_) => None,
+            },
+        };
+        Ok(PhaseState::new(menu_phase(party_state), None))
+    }
+}
+
+/// `Queueing` while the party is matchmaking, otherwise `Menu`.
+fn menu_phase(party_state: Option<PartyState>) -> GamePhase {
+    match party_state {
+        Some(PartyState::Matchmaking | PartyState::MatchmadeGameStarting) => GamePhase::Queueing,
+        _ => GamePhase::Menu,
+    }
+}
+
+/// Tracks the game phase in the background by polling `game_phase` and
+/// reacting to `LogWatcher` events, publishing every transition on a
+/// broadcast channel.
+pub struct PhaseTracker {
+    client: ValorantClient,
+    sender: broadcast::Sender<PhaseChange>,
+    state: Arc<RwLock<PhaseState>>,
+    log_events: Option<broadcast::Receiver<LogEvent>>,
+    interval: Duration,
+    post_game_hold: Duration,
+}
+
+impl PhaseTracker {
+    pub fn new(client: ValorantClient) -> (Self, broadcast::Receiver<PhaseChange>) {
+        let (tx, rx) = broadcast::channel(64);
+        let tracker = Self {
+            client,
+            sender: tx,
+            state: Arc::new(RwLock::new(PhaseState::new(GamePhase::NotRunning, None))),
+            log_events: None,
+            interval: POLL_INTERVAL,
+            post_game_hold: POST_GAME_HOLD,
+        };
+        (tracker, rx)
+    }
+
+    /// React to match start/end from `ShooterGame.log` instead of waiting for the next poll.
+    pub fn with_log_events(mut self, events: broadcast::Receiver<LogEvent>) -> Self {
+        self.log_events = Some(events);
+        self
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// How long to report `PostGame` after a match ends.
+    pub fn with_post_game_hold(mut self, hold: Duration) -> Self {
+        self.post_game_hold = hold;
+        self
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<PhaseChange> {
+        self.sender.subscribe()
+    }
+
+    pub fn sender(&self) -> broadcast::Sender<PhaseChange> {
+        self.sender.clone()
+    }
+
+    pub async fn current(&self) -> PhaseState {
+        self.state.read().await.clone()
+    }
+
+    /// Spawn the tracking task and return a handle to the current state.
+    /// Must be called from a Tokio runtime.
+    pub fn start(mut self) -> Arc<RwLock<PhaseState>> {
+        let state = self.state.clone();
+        let mut log_events = self.log_events.take();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            let mut ended_at: Option<Instant> = None;
+            loop {
+                let match_ended = tokio::select! {
+                    _ = ticker.tick() => false,
+                    event = recv_log(&mut log_events) => match event {
+                        LogEvent::MatchEnded { .. } => true,
+                        LogEvent::GameplayStarted => false,
+                        _ => continue,
+                    },
+                };
+                let current = self.state.read().await.clone();
+                let next = if match_ended && current.phase == GamePhase::InGame {
+                    PhaseState::new(GamePhase::PostGame, current.match_id.clone())
+                } else {
+                    // Keep the last known phase through transient API errors.
+                    let Ok(detected) = self.client.game_phase().await else { continue };
+                    self.resolve(&current, detected, ended_at)
+                };
+                if next.phase == GamePhase::PostGame && current.phase != GamePhase::PostGame {
+                    ended_at = Some(Instant::now());
+                }
+                self.publish(current, next).await;
+            }
+        });
+        state
+    }
+
+    /// Fold a fresh detection into the current state, inserting `PostGame`
+    /// between the end of a match and the menu.
+    fn resolve(&self, current: &PhaseState, detected: PhaseState, ended_at: Option<Instant>) -> PhaseState {
+        match (current.phase, detected.phase) {
+            (GamePhase::InGame, GamePhase::Menu | GamePhase::Queueing) => {
+                PhaseState::new(GamePhase::PostGame, current.match_id.clone())
+            }
+            // The coregame session can linger after the log reports the end.
+            (GamePhase::PostGame, GamePhase::InGame) if detected.match_id == current.match_id => current.clone(),
+            (GamePhase::PostGame, GamePhase::Menu)
+                if ended_at.is_some_and(|t| t.elapsed() < self.post_game_hold) => current.clone(),
+            _ => detected,
+        }
+    }
+
+    async fn publish(&self, current: PhaseState, next: PhaseState) {
+        if next == current {
+            return;
+        }
+        *self.state.write().await = next.clone();
+        let _ = self.sender.send(PhaseChange {
+            previous: current.phase,
+            phase: next.phase,
+            match_id: next.match_id,
+        });
+    }
+}
+
+/// Next log event, or never when there is no log feed (or it closed).
+async fn recv_log(events: &mut Option<broadcast::Receiver<LogEvent>>) -> LogEvent {
+    loop {
+        match events {
+            Some(rx) => match rx.recv().await {
+                Ok(event) => return event,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => *events = None,
+            },
+            None => std::future::pending::<()>().await,
+        }
+    }
+}
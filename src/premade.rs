@@ -0,0 +1,78 @@
+//! Premade detection: players on the same team who share a party ID queued
+//! together.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::models::pregame::PreGameMatch;
+use crate::models::session::CoreGameMatch;
+
+/// Two or more players on one team who queued together.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Premade {
+    /// Position in `Premades::groups`; use it to pick a colour.
+    pub index: usize,
+    pub party_id: String,
+    pub team_id: String,
+    /// PUUIDs, in match order.
+    pub members: Vec<String>,
+}
+
+/// Premade groups in a match. Groups are ordered by team, then party ID, so
+/// indices stay the same for every poll of the same match.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Premades {
+    pub groups: Vec<Premade>,
+}
+
+impl Premades {
+    /// Group `(puuid, team_id, party_id)` triples. Solo players and players
+    /// without a party ID are left out.
+    pub fn from_players<'a>(players: impl IntoIterator<Item = (&'a str, &'a str, &'a str)>) -> Self {
+        let mut parties: BTreeMap<(&str, &str), Vec<String>> = BTreeMap::new();
+        for (puuid, team_id, party_id) in players {
+            if !party_id.is_empty() {
+                parties.entry((team_id, party_id)).or_default().push(puuid.to_string());
+            }
+        }
+        let groups = parties.into_iter()
+            .filter(|(_, members)| members.len() > 1)
+            .enumerate()
+            .map(|(index, ((team_id, party_id), members))| Premade {
+                index,
+                party_id: party_id.to_string(),
+                team_id: team_id.to_string(),
+                members,
+            })
+            .collect();
+        Self { groups }
+    }
+
+    /// Premades on both teams of a live match. Coaches are ignored.
+    pub fn from_coregame(game: &CoreGameMatch) -> Self {
+        Self::from_players(game.players.iter()
+            .filter(|p| !p.is_coach)
+            .map(|p| (p.puuid.as_str(), p.team_id.as_str(), p.party_id.as_str())))
+    }
+
+    /// Premades visible in agent select (usually just the ally team).
+    pub fn from_pregame(pregame: &PreGameMatch) -> Self {
+        Self::from_players(pregame.ally_team.iter()
+            .chain(pregame.enemy_team.iter())
+            .flat_map(|team| team.players.iter()
+                .map(|p| (p.puuid.as_str(), team.team_id.as_str(), p.party_id.as_str()))))
+    }
+
+    pub fn group_of(&self, puuid: &str) -> Option<&Premade> {
+        self.groups.iter().find(|g| g.members.iter().any(|m| m == puuid))
+    }
+
+    /// Group index of a player, or `None` if they queued solo.
+    pub fn index_of(&self, puuid: &str) -> Option<usize> {
+        self.group_of(puuid).map(|g| g.index)
+    }
+
+    pub fn on_team<'a>(&'a self, team_id: &'a str) -> impl Iterator<Item = &'a Premade> {
+        self.groups.iter().filter(move |g| g.team_id == team_id)
+    }
+}
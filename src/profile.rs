@@ -0,0 +1,223 @@
+//! Aggregated performance over a player's recent matches.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio_stream::StreamExt;
+
+use crate::analytics::{self, percent, ratio};
+use crate::client::ValorantClient;
+use crate::error::ValorantError;
+use crate::models::match_data::{MatchDetails, MatchHistoryEntry, Queue};
+
+/// Match details requested at once; PD rate-limits bursts.
+const FETCH_CONCURRENCY: usize = 4;
+/// Most competitive updates PD returns per request.
+const RR_PAGE_SIZE: u32 = 20;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    pub puuid: String,
+    /// Matches the player actually appeared in.
+    pub matches: u32,
+    pub wins: u32,
+    pub win_rate: f64,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub kd: f64,
+    pub acs: f64,
+    pub adr: f64,
+    pub headshot_pct: f64,
+    /// Most played first.
+    pub agents: Vec<AgentSummary>,
+    /// Most played first.
+    pub maps: Vec<MapSummary>,
+    /// RR change per competitive game among the aggregated matches, newest first.
+    pub rr_changes: Vec<i32>,
+    /// Sum of `rr_changes`.
+    pub rr_trend: i32,
+    /// Matches whose details couldn't be fetched; left out of every total.
+    #[serde(default)]
+    pub failed_matches: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AgentSummary {
+    pub character_id: String,
+    pub matches: u32,
+    pub wins: u32,
+    pub win_rate: f64,
+    pub kd: f64,
+    pub acs: f64,
+    #[serde(skip)]
+    totals: Totals,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MapSummary {
+    pub map_id: String,
+    pub matches: u32,
+    pub wins: u32,
+    pub win_rate: f64,
+}
+
+/// Running sums the averages are computed from.
+#[derive(Debug, Clone, Copy, Default)]
+struct Totals {
+    rounds: u32,
+    score: u32,
+    kills: u32,
+    deaths: u32,
+}
+
+impl PlayerProfile {
+    /// Fetch the last `count` matches (any queue) and the RR earned in the
+    /// competitive ones, then aggregate. Match details are fetched concurrently
+    /// and cached on the client; a match that fails to load is recorded in
+    /// `failed_matches` instead of failing the whole profile.
+    pub async fn build(client: &ValorantClient, puuid: &str, count: u32) -> Result<Self, ValorantError> {
+        let history: Vec<MatchHistoryEntry> = client.match_history_stream(puuid, None)
+            .take(count as usize)
+            .collect::<Result<_, _>>()
+            .await?;
+
+        let permits = Arc::new(Semaphore::new(FETCH_CONCURRENCY));
+        let mut tasks = JoinSet::new();
+        for (i, entry) in history.iter().enumerate() {
+            let client = client.clone();
+            let permits = permits.clone();
+            let match_id = entry.match_id.clone();
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                (i, client.match_details(&match_id).await)
+            });
+        }
+        let mut details: Vec<Option<MatchDetails>> = vec![None; history.len()];
+        let mut failed = Vec::new();
+        while let Some(joined) = tasks.join_next().await {
+            let (i, result) = joined.unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()));
+            match result {
+                Ok(match_details) => details[i] = Some(match_details),
+                Err(_) => failed.push(history[i].match_id.clone()),
+            }
+        }
+
+        let competitive: Vec<&str> = history.iter()
+            .filter(|e| e.queue_id == Queue::Competitive)
+            .map(|e| e.match_id.as_str())
+            .collect();
+        let mut profile = Self::from_matches(puuid, details.iter().flatten());
+        profile.rr_changes = Self::rr_changes(client, puuid, &competitive).await?;
+        profile.rr_trend = profile.rr_changes.iter().sum();
+        profile.failed_matches = failed;
+        Ok(profile)
+    }
+
+    /// RR earned in `match_ids`, newest first. They are the player's latest
+    /// competitive games, so the newest `match_ids.len()` updates cover them.
+    async fn rr_changes(client: &ValorantClient, puuid: &str, match_ids: &[&str]) -> Result<Vec<i32>, ValorantError> {
+        let wanted = match_ids.len() as u32;
+        let mut changes = Vec::new();
+        let mut start = 0;
+        while start < wanted {
+            let end = wanted.min(start + RR_PAGE_SIZE);
+            let page = client.competitive_updates(puuid, start, end, Some(Queue::Competitive)).await?;
+            if page.matches.is_empty() {
+                break;
+            }
+            changes.extend(page.matches.iter()
+                .filter(|m| match_ids.contains(&m.match_id.as_str()))
+                .map(|m| m.rr_earned));
+            start = end;
+        }
+        Ok(changes)
+    }
+
+    /// Aggregate already-fetched matches. Matches without the player are skipped.
+    pub fn from_matches<'a>(puuid: &str, matches: impl IntoIterator<Item = &'a MatchDetails>) -> Self {
+        let mut profile = Self { puuid: puuid.to_string(), ..Default::default() };
+        let mut totals = Totals::default();
+        let (mut damage, mut headshots, mut shots) = (0u32, 0u32, 0u32);
+        let mut agents: HashMap<String, AgentSummary> = HashMap::new();
+        let mut maps: HashMap<String, MapSummary> = HashMap::new();
+
+        for details in matches {
+            let Some(player) = details.player(puuid) else { continue };
+            let Some(stats) = analytics::player_stats(details, puuid) else { continue };
+            let won = details.team(&player.team_id).is_some_and(|t| t.won);
+
+            profile.matches += 1;
+            profile.wins += won as u32;
+            profile.kills += stats.kills;
+            profile.deaths += stats.deaths;
+            profile.assists += stats.assists;
+            totals.add(&stats);
+            damage += stats.damage_dealt;
+            headshots += stats.headshots;
+            shots += stats.headshots + stats.bodyshots + stats.legshots;
+
+            let agent = agents.entry(player.character_id.clone()).or_insert_with(|| AgentSummary {
+                character_id: player.character_id.clone(),
+                ..Default::default()
+            });
+            agent.matches += 1;
+            agent.wins += won as u32;
+            agent.totals.add(&stats);
+
+            let map = maps.entry(details.match_info.map_id.clone()).or_insert_with(|| MapSummary {
+                map_id: details.match_info.map_id.clone(),
+                ..Default::default()
+            });
+            map.matches += 1;
+            map.wins += won as u32;
+        }
+
+        profile.win_rate = percent(profile.wins, profile.matches);
+        profile.kd = totals.kd();
+        profile.acs = ratio(totals.score, totals.rounds);
+        profile.adr = ratio(damage, totals.rounds);
+        profile.headshot_pct = percent(headshots, shots);
+
+        profile.agents = agents.into_values()
+            .map(|mut a| {
+                a.win_rate = percent(a.wins, a.matches);
+                a.kd = a.totals.kd();
+                a.acs = ratio(a.totals.score, a.totals.rounds);
+                a
+            })
+            .collect();
+        profile.agents.sort_by(|a, b| b.matches.cmp(&a.matches).then(b.wins.cmp(&a.wins)));
+
+        profile.maps = maps.into_values()
+            .map(|mut m| {
+                m.win_rate = percent(m.wins, m.matches);
+                m
+            })
+            .collect();
+        profile.maps.sort_by(|a, b| b.matches.cmp(&a.matches).then(b.wins.cmp(&a.wins)));
+
+        profile
+    }
+
+    /// Up to `n` agents, most played first.
+    pub fn most_played_agents(&self, n: usize) -> &[AgentSummary] {
+        &self.agents[..n.min(self.agents.len())]
+    }
+}
+
+impl Totals {
+    fn add(&mut self, stats: &analytics::DerivedStats) {
+        self.rounds += stats.rounds_played;
+        self.score += stats.score;
+        self.kills += stats.kills;
+        self.deaths += stats.deaths;
+    }
+
+    /// Kills per death; with no deaths, just the kills.
+    fn kd(&self) -> f64 {
+        ratio(self.kills, self.deaths.max(1))
+    }
+}
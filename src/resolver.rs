@@ -0,0 +1,71 @@
+use crate::auth::LockfileData;
+
+/// Maps each Riot API cluster to a base URL (scheme + host, no trailing slash).
+/// Swap the implementation to point the client at a proxy or mock server.
+pub trait EndpointResolver: Send + Sync {
+    /// Local Riot Client API (auth tokens, sessions, chat).
+    fn local(&self, lockfile: &LockfileData) -> String;
+    /// Player Data: match history, MMR, names, store.
+    fn pd(&self, shard: &str) -> String;
+    /// Game Lobby Zone: pregame, coregame, parties.
+    fn glz(&self, region: &str, shard: &str) -> String;
+    /// Shared services: content and client config.
+    fn shared(&self, shard: &str) -> String;
+}
+
+/// The live Riot hosts. Used unless the builder is given another resolver.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RiotEndpoints;
+
+impl EndpointResolver for RiotEndpoints {
+    fn local(&self, lockfile: &LockfileData) -> String {
+        format!("{}://127.0.0.1:{}", lockfile.protocol, lockfile.port)
+    }
+
+    fn pd(&self, shard: &str) -> String {
+        format!("https://pd.{}.a.pvp.net", shard)
+    }
+
+    fn glz(&self, region: &str, shard: &str) -> String {
+        format!("https://glz-{}-1.{}.a.pvp.net", region, shard)
+    }
+
+    fn shared(&self, shard: &str) -> String {
+        format!("https://shared.{}.a.pvp.net", shard)
+    }
+}
+
+/// Fixed base URLs that ignore shard, region and lockfile port.
+#[derive(Debug, Clone)]
+pub struct StaticEndpoints {
+    pub local: String,
+    pub pd: String,
+    pub glz: String,
+    pub shared: String,
+}
+
+impl StaticEndpoints {
+    /// Route every cluster to the same base URL.
+    pub fn all(base: impl Into<String>) -> Self {
+        let base = base.into();
+        Self { local: base.clone(), pd: base.clone(), glz: base.clone(), shared: base }
+    }
+}
+
+impl EndpointResolver for StaticEndpoints {
+    fn local(&self, _lockfile: &LockfileData) -> String {
+        self.local.clone()
+    }
+
+    fn pd(&self, _shard: &str) -> String {
+        self.pd.clone()
+    }
+
+    fn glz(&self, _region: &str, _shard: &str) -> String {
+        self.glz.clone()
+    }
+
+    fn shared(&self, _shard: &str) -> String {
+        self.shared.clone()
+    }
+}
@@ -0,0 +1,193 @@
+//! Push events from the Riot Client's local WAMP WebSocket.
+
+use base64::Engine;
+use futures_util::SinkExt;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::Connector;
+
+use crate::client::ValorantClient;
+use crate::error::ValorantError;
+
+/// Wait between reconnect attempts, e.g. while the Riot Client restarts.
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+/// WAMP opcodes used by the Riot Client.
+const WAMP_SUBSCRIBE: u8 = 5;
+const WAMP_EVENT: u8 = 8;
+
+/// Event names accepted by `subscribe_events`.
+pub mod events {
+    /// Every local API event.
+    pub const ALL: &str = "OnJsonApiEvent";
+    pub const PRESENCES: &str = "OnJsonApiEvent_chat_v4_presences";
+    pub const FRIENDS: &str = "OnJsonApiEvent_chat_v4_friends";
+    pub const FRIEND_REQUESTS: &str = "OnJsonApiEvent_chat_v4_friendrequests";
+    pub const CHAT_MESSAGES: &str = "OnJsonApiEvent_chat_v6_messages";
+    pub const CONVERSATIONS: &str = "OnJsonApiEvent_chat_v6_conversations";
+    pub const SESSIONS: &str = "OnJsonApiEvent_product-session_v1_external-sessions";
+    pub const ENTITLEMENTS_TOKEN: &str = "OnJsonApiEvent_entitlements_v1_token";
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LocalEventType {
+    Create,
+    Update,
+    Delete,
+    #[serde(other)]
+    Unknown,
+}
+
+/// One event pushed by the local WebSocket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalEvent {
+    /// The subscription it arrived on, e.g. `OnJsonApiEvent_chat_v4_presences`.
+    #[serde(skip_deserializing)]
+    pub event: String,
+    /// Local API path the event is about, e.g. `/chat/v4/presences`.
+    #[serde(rename = "uri")]
+    pub uri: String,
+    #[serde(rename = "eventType")]
+    pub event_type: LocalEventType,
+    #[serde(rename = "data", default)]
+    pub data: serde_json::Value,
+}
+
+impl LocalEvent {
+    /// Decode `data` into the model for this event's URI.
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T, ValorantError> {
+        Ok(T::deserialize(&self.data)?)
+    }
+
+    /// Parse a `[8, event, payload]` WAMP frame.
+    fn parse(text: &str) -> Option<Self> {
+        let (opcode, event, payload): (u8, String, serde_json::Value) = serde_json::from_str(text).ok()?;
+        if opcode != WAMP_EVENT {
+            return None;
+        }
+        let mut parsed: LocalEvent = serde_json::from_value(payload).ok()?;
+        parsed.event = event;
+        Some(parsed)
+    }
+}
+
+impl ValorantClient {
+    /// Subscribe to local API events, e.g. `events::PRESENCES`. The connection
+    /// uses the current lockfile credentials and is re-established whenever it
+    /// drops, so the stream only ends when it is dropped.
+    pub fn subscribe_events(&self, events: &[&str]) -> ReceiverStream<LocalEvent> {
+        let events: Vec<String> = events.iter().map(|e| e.to_string()).collect();
+        let (tx, rx) = mpsc::channel(64);
+        let client = self.clone();
+        tokio::spawn(async move {
+            while !tx.is_closed() {
+                tokio::select! {
+                    _ = client.forward_events(&events, &tx) => {}
+                    _ = tx.closed() => break,
+                }
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        });
+        ReceiverStream::new(rx)
+    }
+
+    /// Subscribe to a single event and decode each payload as `T`.
+    /// Events whose data doesn't match `T` (e.g. deletes) are skipped.
+    pub fn subscribe_typed<T>(&self, event: &str) -> impl tokio_stream::Stream<Item = (LocalEvent, T)>
+    where
+        T: DeserializeOwned,
+    {
+        self.subscribe_events(&[event])
+            .filter_map(|e| e.data_as::<T>().ok().map(|data| (e, data)))
+    }
+
+    /// One connection: subscribe, then forward events until the socket closes.
+    async fn forward_events(&self, events: &[String], tx: &mpsc::Sender<LocalEvent>) {
+        let lockfile = self.lockfile.read().await.clone();
+        let url = self.local_url().await
+            .replacen("https://", "wss://", 1)
+            .replacen("http://", "ws://", 1);
+        let Ok(mut request) = url.into_client_request() else { return };
+        let credentials = base64::engine::general_purpose::STANDARD.encode(format!("riot:{}", lockfile.password));
+        let Ok(header) = format!("Basic {}", credentials).parse() else { return };
+        request.headers_mut().insert("Authorization", header);
+
+        let connector = Connector::Rustls(Arc::new(insecure_tls_config()));
+        let Ok((mut socket, _)) = tokio_tungstenite::connect_async_tls_with_config(
+            request, None, false, Some(connector),
+        ).await else { return };
+
+        for event in events {
+            let frame = json!([WAMP_SUBSCRIBE, event]).to_string();
+            if socket.send(Message::text(frame)).await.is_err() {
+                return;
+            }
+        }
+        while let Some(Ok(message)) = socket.next().await {
+            let Message::Text(text) = message else { continue };
+            if let Some(event) = LocalEvent::parse(&text) {
+                if tx.send(event).await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// The local API serves a self-signed certificate, so (as with the HTTP
+/// client) certificate checks are skipped. Only ever used for 127.0.0.1.
+fn insecure_tls_config() -> rustls::ClientConfig {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    rustls::ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .expect("ring supports the default protocol versions")
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyCert(provider)))
+        .with_no_client_auth()
+}
+
+#[derive(Debug)]
+struct AcceptAnyCert(Arc<rustls::crypto::CryptoProvider>);
+
+impl rustls::client::danger::ServerCertVerifier for AcceptAnyCert {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &rustls::pki_types::CertificateDer<'_>,
+        _intermediates: &[rustls::pki_types::CertificateDer<'_>],
+        _server_name: &rustls::pki_types::ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: rustls::pki_types::UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        Ok(rustls::client::danger::ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
@@ -0,0 +1,132 @@
+use val_local_api::analytics::{derived_stats, DerivedStats};
+use val_local_api::models::match_data::MatchDetails;
+
+const SELF: &str = "5c0b1d2e-8f3a-4e6b-9a1c-3d2e4f5a6b7c";
+
+fn fixture() -> Vec<DerivedStats> {
+    let details: MatchDetails = serde_json::from_str(include_str!("../src/mock/fixtures/match_details.json")).unwrap();
+    derived_stats(&details)
+}
+
+fn by_prefix<'a>(stats: &'a [DerivedStats], prefix: &str) -> &'a DerivedStats {
+    stats.iter().find(|s| s.puuid.starts_with(prefix)).unwrap()
+}
+
+#[test]
+fn combat_score_and_shots() {
+    let stats = fixture();
+    assert_eq!(stats.len(), 10);
+
+    let me = by_prefix(&stats, SELF);
+    assert_eq!(me.acs, 1146.0 / 8.0);
+    assert_eq!(me.adr, 596.0 / 8.0);
+    let shots = me.headshot_pct + me.bodyshot_pct + me.legshot_pct;
+    assert!((shots - 100.0).abs() < 1e-9);
+    assert!(stats.iter().all(|s| (0.0..=100.0).contains(&s.kast)));
+}
+
+#[test]
+fn opening_duels_and_multi_kills() {
+    let stats = fixture();
+    assert_eq!(by_prefix(&stats, "7fd1").first_kills, 4);
+    assert_eq!(by_prefix(&stats, "3b9d").first_kills, 1);
+    assert_eq!(by_prefix(&stats, "5dbf").multi_kills.triple, 1);
+
+    let first_kills: u32 = stats.iter().map(|s| s.first_kills).sum();
+    let first_deaths: u32 = stats.iter().map(|s| s.first_deaths).sum();
+    assert_eq!((first_kills, first_deaths), (8, 8));
+}
+
+#[test]
+fn trades_and_clutches() {
+    let stats = fixture();
+    // Round 5: killed by 80e2, who died to 2a8c 2.3s later.
+    assert_eq!(by_prefix(&stats, SELF).traded_deaths, 1);
+    assert_eq!(by_prefix(&stats, "1f7b").trade_kills, 1);
+    assert_eq!(by_prefix(&stats, "2a8c").trade_kills, 2);
+
+    let clutcher = by_prefix(&stats, "2a8c");
+    assert_eq!((clutcher.clutch_attempts, clutcher.clutches_won), (2, 0));
+}
+
+#[test]
+fn one_kill_trading_two_deaths_counts_once() {
+    let mut details: MatchDetails = serde_json::from_str(include_str!("../src/mock/fixtures/match_details.json")).unwrap();
+    let team_of = |puuid: &str| details.players.iter().find(|p| p.subject == puuid).unwrap().team_id.clone();
+    let my_team = team_of(SELF);
+    let (allies, enemies): (Vec<_>, Vec<_>) = details.players.iter()
+        .map(|p| p.subject.clone())
+        .partition(|p| team_of(p) == my_team);
+    let (first, second, trader, enemy) = (&allies[0], &allies[1], &allies[2], &enemies[0]);
+
+    // Keep one round: the enemy kills two allies, then a third ally kills the enemy.
+    details.round_results.truncate(1);
+    let round = &mut details.round_results[0];
+    let template = round.player_stats.iter().flat_map(|s| &s.kills).next().unwrap().clone();
+    let kill = |round_time, killer: &str, victim: &str| {
+        let mut kill = template.clone();
+        kill.round_time = round_time;
+        kill.killer = Some(killer.to_string());
+        kill.victim = victim.to_string();
+        kill.assistants.clear();
+        kill
+    };
+    for stats in &mut round.player_stats {
+        stats.kills.clear();
+    }
+    round.player_stats[0].kills = vec![
+        kill(10_000, enemy, first),
+        kill(11_000, enemy, second),
+        kill(12_000, trader, enemy),
+    ];
+
+    let stats = derived_stats(&details);
+    assert_eq!(by_prefix(&stats, trader).trade_kills, 1);
+    assert_eq!(by_prefix(&stats, first).traded_deaths, 1);
+    assert_eq!(by_prefix(&stats, second).traded_deaths, 1);
+}
+
+#[test]
+fn both_players_in_a_one_v_one_attempt_the_clutch() {
+    let mut details: MatchDetails = serde_json::from_str(include_str!("../src/mock/fixtures/match_details.json")).unwrap();
+    let team_of = |puuid: &str| details.players.iter().find(|p| p.subject == puuid).unwrap().team_id.clone();
+    let my_team = team_of(SELF);
+    let (allies, enemies): (Vec<_>, Vec<_>) = details.players.iter()
+        .map(|p| p.subject.clone())
+        .partition(|p| team_of(p) == my_team);
+
+    // Keep one round: four players per team die, the last ally wins the 1v1.
+    details.round_results.truncate(1);
+    let round = &mut details.round_results[0];
+    round.winning_team = my_team.clone();
+    let template = round.player_stats.iter().flat_map(|s| &s.kills).next().unwrap().clone();
+    let kill = |round_time, killer: &str, victim: &str| {
+        let mut kill = template.clone();
+        kill.round_time = round_time;
+        kill.killer = Some(killer.to_string());
+        kill.victim = victim.to_string();
+        kill.assistants.clear();
+        kill
+    };
+    for stats in &mut round.player_stats {
+        stats.kills.clear();
+    }
+    let (ally, enemy) = (&allies[4], &enemies[4]);
+    round.player_stats[0].kills = (0..4)
+        .flat_map(|i| [
+            kill(10_000 + 20_000 * i, ally, &enemies[i as usize]),
+            kill(20_000 + 20_000 * i, enemy, &allies[i as usize]),
+        ])
+        .chain([kill(100_000, ally, enemy)])
+        .collect();
+
+    let stats = derived_stats(&details);
+    let clutches = |puuid: &str| {
+        let s = by_prefix(&stats, puuid);
+        (s.clutch_attempts, s.clutches_won)
+    };
+    assert_eq!(clutches(ally), (1, 1));
+    assert_eq!(clutches(enemy), (1, 0));
+    let attempts: u32 = stats.iter().map(|s| s.clutch_attempts).sum();
+    assert_eq!(attempts, 2);
+}
@@ -0,0 +1,689 @@
+#![cfg(feature = "mock")]
+
+use tokio_stream::StreamExt;
+use val_local_api::mock::{MockServer, MOCK_PUUID};
+use val_local_api::models::account_xp::XpSourceKind;
+use val_local_api::models::chat::ChatChannel;
+use val_local_api::models::contracts::BATTLEPASS_MAX_TIER;
+use val_local_api::models::inventory::ItemType;
+use val_local_api::models::match_data::{MatchHistoryQuery, Queue};
+use val_local_api::models::loadout::PlayerLoadout;
+use val_local_api::models::lobby::LobbyPhase;
+use val_local_api::models::player::CompetitiveTier;
+use val_local_api::models::pregame::CharacterSelectionState;
+use val_local_api::models::presence::SessionLoopState;
+use val_local_api::models::store::Currency;
+use val_local_api::premade::Premades;
+use val_local_api::profile::PlayerProfile;
+use val_local_api::websocket::events;
+use val_local_api::{ConnectionState, GamePhase, LocalEventType, LogEvent, LogWatcher, PhaseChange, PhaseTracker, ValorantClient, ValorantError};
+
+#[tokio::test]
+async fn connects_and_reads_identity() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+    assert_eq!(client.puuid().await, MOCK_PUUID);
+
+    let auth = client.get_auth().await;
+    assert_eq!((auth.region.as_str(), auth.shard.as_str()), ("eu", "eu"));
+}
+
+#[tokio::test]
+async fn lockfile_watcher_reconnects() {
+    let mock = MockServer::start().await.unwrap();
+    let dir = std::env::temp_dir().join(format!("val-local-api-lockfile-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("lockfile");
+    let write_lockfile = |password: &str| {
+        let contents = format!("Riot Client:1234:{}:{}:http", mock.lockfile().port, password);
+        std::fs::write(&path, contents).unwrap();
+    };
+    write_lockfile("mock-password");
+
+    let client = ValorantClient::builder()
+        .lockfile_path(&path)
+        .endpoints(mock.endpoints())
+        .build().await.unwrap();
+    assert_eq!(client.connection_state().await, ConnectionState::Connected);
+    let mut states = client.subscribe_connection();
+    client.watch_lockfile().unwrap();
+
+    // The Riot Client restarted with a new password.
+    write_lockfile("rotated-password");
+    assert_eq!(next_state(&mut states).await, ConnectionState::Connecting);
+    assert_eq!(next_state(&mut states).await, ConnectionState::Connected);
+
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(next_state(&mut states).await, ConnectionState::Disconnected);
+    assert_eq!(client.connection_state().await, ConnectionState::Disconnected);
+    let _ = std::fs::remove_dir(&dir);
+}
+
+#[tokio::test]
+async fn builder_log_path_and_client_version() {
+    let mock = MockServer::start().await.unwrap();
+    mock.set_status(reqwest::Method::GET, "/riotclient/region-locale", reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+    let log = std::env::temp_dir().join(format!("val-local-api-{}.log", std::process::id()));
+    std::fs::write(&log, "[Request] GET https://glz-br-1.na.a.pvp.net/parties/v1/players/x\n").unwrap();
+    let builder = || ValorantClient::builder().lockfile(mock.lockfile()).endpoints(mock.endpoints());
+
+    // With region-locale down, the region comes from the configured log.
+    let client = builder().log_path(&log).build().await.unwrap();
+    let auth = client.get_auth().await;
+    assert_eq!((auth.region.as_str(), auth.shard.as_str()), ("br", "na"));
+    std::fs::remove_file(&log).unwrap();
+
+    let invalid = builder().log_path(&log).client_version("release\n10.0").build().await;
+    assert!(matches!(invalid, Err(ValorantError::InvalidClientVersion(_))));
+}
+
+#[tokio::test]
+async fn refreshes_token_on_401() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+    let tokens = || mock.request_count(reqwest::Method::GET, "/entitlements/v1/token");
+    let before = tokens();
+
+    mock.set_status_once(reqwest::Method::GET, "/mmr/v1/players/*", reqwest::StatusCode::UNAUTHORIZED);
+    assert!(client.mmr(MOCK_PUUID).await.is_ok());
+    assert_eq!(tokens(), before + 1);
+    assert_eq!(mock.request_count(reqwest::Method::GET, "/mmr/v1/players/*"), 2);
+
+    // A 403 is a permission error: no refresh and no replayed POST.
+    mock.set_status(reqwest::Method::POST, "/pregame/v1/matches/*/lock/*", reqwest::StatusCode::FORBIDDEN);
+    assert!(client.pregame_lock_agent("match", "agent").await.is_err());
+    assert_eq!(tokens(), before + 1);
+    assert_eq!(mock.request_count(reqwest::Method::POST, "/pregame/v1/matches/*/lock/*"), 1);
+}
+
+#[tokio::test]
+async fn refreshes_token_before_it_expires() {
+    let mock = MockServer::start().await.unwrap();
+    mock.set_token_expiry(std::time::Duration::from_secs(60));
+    let client = mock.client().await.unwrap();
+    let tokens = || mock.request_count(reqwest::Method::GET, "/entitlements/v1/token");
+    assert_eq!(tokens(), 1);
+
+    // The first token is inside the refresh margin, so the next request
+    // refreshes it; the new one lasts an hour.
+    mock.set_token_expiry(std::time::Duration::from_secs(3600));
+    client.mmr(MOCK_PUUID).await.unwrap();
+    assert_eq!(tokens(), 2);
+    client.mmr(MOCK_PUUID).await.unwrap();
+    assert_eq!(tokens(), 2);
+}
+
+#[tokio::test]
+async fn live_match_endpoints() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let player = client.coregame_player(MOCK_PUUID).await.unwrap();
+    let live = client.coregame_match(&player.match_id).await.unwrap();
+    assert_eq!(live.players.len(), 10);
+    assert!(live.players.iter().any(|p| p.puuid == MOCK_PUUID));
+
+    let loadouts = client.coregame_loadouts(&player.match_id).await.unwrap();
+    let mine = loadouts.for_player(MOCK_PUUID).unwrap();
+    assert!(mine.loadout.guns().iter().all(|g| !g.skin_id.is_empty()));
+
+    let pregame = client.pregame_player(MOCK_PUUID).await.unwrap();
+    let pregame_match = client.pregame_match(&pregame.match_id).await.unwrap();
+    assert_eq!(pregame_match.id, pregame.match_id);
+    let me = pregame_match.player(MOCK_PUUID).unwrap();
+    assert_eq!(me.selection_state, CharacterSelectionState::Locked);
+
+    let agent = me.character_id.clone();
+    client.pregame_lock_agent(&pregame.match_id, &agent).await.unwrap();
+    client.pregame_quit(&pregame.match_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn not_in_match_on_404() {
+    let mock = MockServer::start().await.unwrap();
+    mock.set_status(reqwest::Method::GET, "/core-game/v1/players/*", reqwest::StatusCode::NOT_FOUND);
+    let client = mock.client().await.unwrap();
+    assert!(matches!(client.coregame_player(MOCK_PUUID).await, Err(ValorantError::NotInMatch)));
+}
+
+#[tokio::test]
+async fn player_data_endpoints() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    // Every page of the fixture holds the same three matches, whatever the queue.
+    let history = client.match_history(MOCK_PUUID, 10).await.unwrap();
+    assert_eq!(history.len(), 10);
+    assert_eq!(history[0].queue_id, Queue::Swiftplay);
+
+    let details = client.match_details(&history[0].match_id).await.unwrap();
+    assert_eq!(details.players.len(), 10);
+    assert_eq!(details.match_info.queue, Queue::Swiftplay);
+    assert!(details.match_info.extra.contains_key("premierMatchInfo"));
+    assert_eq!(details.round_results.len(), 8);
+    let plant = details.round_results[5].plant().unwrap();
+    assert_eq!(plant.site, "B");
+    assert_eq!(details.round_results[5].defuse().unwrap().player, MOCK_PUUID);
+    assert!(details.round_results[0].plant().is_none());
+    let headshots: u32 = details.round_results.iter()
+        .filter_map(|r| r.player_stats(MOCK_PUUID))
+        .flat_map(|s| &s.damage)
+        .map(|d| d.headshots)
+        .sum();
+    assert!(headshots > 0);
+    assert_eq!(details.kills_by(MOCK_PUUID).count() as u32, details.player(MOCK_PUUID).unwrap().stats.kills);
+
+    let names = client.resolve_names(&[MOCK_PUUID.to_string()]).await.unwrap();
+    assert_eq!(names[0].name, "houdyxx");
+
+    let mmr = client.mmr(MOCK_PUUID).await.unwrap();
+    assert_eq!(mmr.latest_ranked_tier(), CompetitiveTier::Platinum1);
+    assert_eq!(mmr.latest_ranked_tier().to_string(), "Platinum 1");
+    let act = &mmr.latest_update.as_ref().unwrap().season_id;
+    assert_eq!(mmr.ranked_rating(act), 42);
+    assert_eq!(mmr.leaderboard_rank(act), None);
+
+    let updates = client.competitive_updates(MOCK_PUUID, 0, 20, Some(Queue::Competitive)).await.unwrap();
+    assert!(updates.matches[1].promoted());
+    assert_eq!(u32::from(updates.matches[1].tier_after), 15);
+
+    let xp = client.account_xp(MOCK_PUUID).await.unwrap();
+    let per_match = xp.correlate(&history);
+    assert!(per_match.iter().all(|(_, entry)| entry.is_some()));
+    let latest = per_match[0].1.unwrap();
+    assert_eq!(latest.xp_from(&XpSourceKind::MatchWin), 1000);
+    assert_eq!(latest.end, xp.progress);
+}
+
+#[tokio::test]
+async fn match_details_without_mode_specific_fields() {
+    let mock = MockServer::start().await.unwrap();
+    let mut details: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/match_details.json")).unwrap();
+    // Deathmatch-style data: no economy or ability casts, plus fields from a newer patch.
+    for player in details["players"].as_array_mut().unwrap() {
+        player["stats"].as_object_mut().unwrap().remove("abilityCasts");
+    }
+    for round in details["roundResults"].as_array_mut().unwrap() {
+        for stats in round["playerStats"].as_array_mut().unwrap() {
+            stats.as_object_mut().unwrap().remove("economy");
+            stats.as_object_mut().unwrap().remove("ability");
+        }
+    }
+    details["roundResults"][0]["playerStats"][0]["damage"][0]["penetrated"] = true.into();
+    mock.set_fixture(reqwest::Method::GET, "/match-details/v1/matches/*", details);
+    let client = mock.client().await.unwrap();
+
+    let details = client.match_details("any").await.unwrap();
+    assert!(details.players[0].stats.ability_casts.is_none());
+    let stats = &details.round_results[0].player_stats[0];
+    assert!(stats.economy.is_none());
+    assert_eq!(stats.damage[0].extra["penetrated"], true);
+}
+
+#[tokio::test]
+async fn party_endpoints() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let player = client.party_player(MOCK_PUUID).await.unwrap();
+    let party = client.party(&player.party_id).await.unwrap();
+    assert_eq!(party.owner().unwrap().puuid, MOCK_PUUID);
+    assert_eq!(party.members.len(), 2);
+
+    client.party_change_queue(&party.id, "swiftplay").await.unwrap();
+    client.party_set_ready(&party.id, MOCK_PUUID, true).await.unwrap();
+}
+
+#[tokio::test]
+async fn store_endpoints() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let store = client.storefront(MOCK_PUUID).await.unwrap();
+    assert_eq!(store.skins_panel.offers.len(), 4);
+    assert!(store.daily_offers_remaining().as_secs() > 0);
+    assert!(store.night_market_remaining().is_some());
+
+    let wallet = client.wallet(MOCK_PUUID).await.unwrap();
+    assert_eq!(wallet.valorant_points(), 2365);
+    assert_eq!(wallet.kingdom_credits(), 7850);
+
+    let offers = client.store_offers().await.unwrap();
+    let (currency, price) = offers.offers[0].price().unwrap();
+    assert_eq!((currency, price), (&Currency::ValorantPoints, 1775));
+}
+
+#[tokio::test]
+async fn loadout_roundtrip() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let mut loadout = client.player_loadout(MOCK_PUUID).await.unwrap();
+    let vandal = loadout.gun_mut("9c82e19d-4575-0200-1a81-3eacf00cf872").unwrap();
+    assert!(vandal.buddy_id.is_some());
+    vandal.buddy_id = None;
+    let saved = client.set_player_loadout(MOCK_PUUID, &loadout).await.unwrap();
+    assert_eq!(saved.guns.len(), loadout.guns.len());
+
+    // Fields the model doesn't know about survive a read-modify-write.
+    let mut raw: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/player_loadout.json")).unwrap();
+    raw["ActiveExpressions"] = serde_json::json!({ "Slot": "emote" });
+    raw["Guns"][0]["StatTrackers"] = serde_json::json!({ "Kills": 12 });
+    let parsed: PlayerLoadout = serde_json::from_value(raw.clone()).unwrap();
+    let written = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(written["ActiveExpressions"], raw["ActiveExpressions"]);
+    assert_eq!(written["Guns"][0]["StatTrackers"], raw["Guns"][0]["StatTrackers"]);
+}
+
+#[tokio::test]
+async fn inventory_endpoints() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let sprays = client.owned_items(MOCK_PUUID, ItemType::Sprays).await.unwrap();
+    assert_eq!(sprays.item_type, ItemType::Sprays);
+
+    let inventory = client.inventory(MOCK_PUUID).await.unwrap();
+    let loadout = client.player_loadout(MOCK_PUUID).await.unwrap();
+    assert!(loadout.guns.iter().all(|g| inventory.owns(&ItemType::SkinLevels, &g.skin_level_id)));
+    assert!(inventory.buddies.entitlements[0].instance_id.is_some());
+}
+
+#[tokio::test]
+async fn contract_progress() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let mut contracts = client.contracts(MOCK_PUUID).await.unwrap();
+    let definitions = client.contract_definitions().await.unwrap();
+    // 2026-10-17T12:00:00Z; the fourth mission is an event mission running into December.
+    contracts.fetched_at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_792_238_400);
+    assert_eq!(contracts.missions.len(), 4);
+    assert_eq!(contracts.weekly_missions().count(), 2);
+    assert_eq!(contracts.daily_missions().count(), 1);
+    assert!(contracts.active_special().is_some());
+    let mut reformatted = contracts.clone();
+    reformatted.missions[0].expiration_time = "2026-10-19t00:00:00.000Z".into();
+    assert_eq!(reformatted.weekly_missions().count(), 2);
+
+    let battlepass = definitions.definitions.iter().find(|d| d.max_level() == BATTLEPASS_MAX_TIER).unwrap();
+    let progress = contracts.contract(&battlepass.id).unwrap();
+    assert_eq!(progress.battlepass_xp_to_next_tier(), Some(14300));
+    assert_eq!(progress.xp_to_next_level(battlepass), progress.battlepass_xp_to_next_tier());
+}
+
+#[tokio::test]
+async fn match_history_paging() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let query = MatchHistoryQuery::latest(20).range(20, 40).queue(Queue::Competitive);
+    let page = client.match_history_page(MOCK_PUUID, &query).await.unwrap();
+    assert_eq!(page.total, 187);
+
+    // The fixture answers every page with the same three matches.
+    let first_five: Vec<_> = client.match_history_stream(MOCK_PUUID, None).take(5).collect().await;
+    assert_eq!(first_five.len(), 5);
+    assert_eq!(first_five[3].as_ref().unwrap().match_id, first_five[0].as_ref().unwrap().match_id);
+    // More than one page's worth takes several requests.
+    assert_eq!(client.match_history(MOCK_PUUID, 25).await.unwrap().len(), 25);
+
+    let mut last_page = serde_json::from_str::<serde_json::Value>(include_str!("../src/mock/fixtures/match_history.json")).unwrap();
+    last_page["Total"] = 3.into();
+    mock.set_fixture(reqwest::Method::GET, "/match-history/v1/history/*", last_page);
+    let all: Vec<_> = client.match_history_stream(MOCK_PUUID, None).collect().await;
+    assert_eq!(all.len(), 3);
+
+    assert_eq!(client.match_history(MOCK_PUUID, 25).await.unwrap().len(), 3);
+    let query = MatchHistoryQuery::latest(0).range(u32::MAX - 1, u32::MAX);
+    assert!(client.match_history_range(MOCK_PUUID, &query).await.is_ok());
+}
+
+#[tokio::test]
+async fn player_profile() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let profile = PlayerProfile::build(&client, MOCK_PUUID, 3).await.unwrap();
+    assert_eq!((profile.matches, profile.wins), (3, 3));
+    assert_eq!(profile.win_rate, 100.0);
+    assert_eq!(profile.kills, 9);
+    assert_eq!(profile.most_played_agents(5).len(), 1);
+    assert_eq!(profile.maps[0].map_id, "/Game/Maps/Ascent/Ascent");
+    // Only the two competitive matches among the three count towards RR.
+    assert_eq!(profile.rr_changes, vec![19, 35]);
+    assert_eq!(profile.rr_trend, 54);
+    assert!(profile.failed_matches.is_empty());
+
+    // Details are cached, so a second build works with the endpoint gone.
+    mock.set_status(reqwest::Method::GET, "/match-details/v1/matches/*", reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(PlayerProfile::build(&client, MOCK_PUUID, 3).await.is_ok());
+
+    // One unavailable match is left out rather than failing the profile.
+    mock.set_fixture(reqwest::Method::GET, "/match-details/v1/matches/*",
+        serde_json::from_str(include_str!("../src/mock/fixtures/match_details.json")).unwrap());
+    mock.set_status_once(reqwest::Method::GET, "/match-details/v1/matches/*", reqwest::StatusCode::NOT_FOUND);
+    let fresh = mock.client().await.unwrap();
+    let profile = PlayerProfile::build(&fresh, MOCK_PUUID, 3).await.unwrap();
+    assert_eq!((profile.matches, profile.failed_matches.len()), (2, 1));
+}
+
+#[tokio::test]
+async fn live_lobby_in_game() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let lobby = client.live_lobby().await.unwrap();
+    assert_eq!(lobby.phase, LobbyPhase::InGame);
+    assert_eq!(lobby.queue_id, "competitive");
+    assert_eq!(lobby.players.len(), 10);
+    assert_eq!(lobby.allies().count(), 5);
+
+    let me = lobby.player(MOCK_PUUID).unwrap();
+    assert!(me.is_self && me.is_ally);
+    assert!(me.name.is_some());
+    assert!(me.party_id.is_some());
+    assert!(!me.skins.is_empty());
+    assert!(me.current_tier.is_ranked());
+    assert_ne!(me.previous_act_tier, CompetitiveTier::Unranked);
+
+    assert!(lobby.allies().all(|p| p.name.is_some()));
+    for enemy in lobby.enemies() {
+        assert_eq!(enemy.name.is_none(), enemy.incognito);
+    }
+    assert_eq!(lobby.enemies().filter(|p| p.account_level.is_none()).count(), 1);
+}
+
+#[tokio::test]
+async fn live_lobby_ranks_are_for_the_current_act() {
+    let mock = MockServer::start().await.unwrap();
+    // Last ranked game was in the previous act; nothing played this act.
+    let mut mmr: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/mmr.json")).unwrap();
+    let current_act = mmr["LatestCompetitiveUpdate"]["SeasonID"].as_str().unwrap().to_string();
+    let previous_act = "16118998-4705-5813-86dd-0292a2439d90";
+    mmr["LatestCompetitiveUpdate"]["SeasonID"] = previous_act.into();
+    mmr["QueueSkills"]["competitive"]["SeasonalInfoBySeasonID"].as_object_mut().unwrap().remove(&current_act);
+    mock.set_fixture(reqwest::Method::GET, "/mmr/v1/players/*", mmr);
+    let client = mock.client().await.unwrap();
+
+    let lobby = client.live_lobby().await.unwrap();
+    let me = lobby.player(MOCK_PUUID).unwrap();
+    assert_eq!(me.current_tier, CompetitiveTier::Unranked);
+    assert_eq!(me.ranked_rating, 0);
+    assert_eq!(me.previous_act_tier, CompetitiveTier::Platinum2);
+
+    mock.set_status(reqwest::Method::GET, "/content-service/v3/content", reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(client.live_lobby().await.is_err());
+}
+
+#[tokio::test]
+async fn peak_tier_renumbers_acts_before_episode_5() {
+    let mock = MockServer::start().await.unwrap();
+    let old_act = "a16955a5-4ad0-f761-5e9e-389df1c892fb";
+    let mut content: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/content.json")).unwrap();
+    content["Seasons"].as_array_mut().unwrap().push(serde_json::json!({
+        "ID": old_act, "Name": "ACT III", "Type": "act",
+        "StartTime": "2022-01-11T00:00:00Z", "EndTime": "2022-03-01T00:00:00Z", "IsActive": false,
+    }));
+    let mut mmr: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/mmr.json")).unwrap();
+    // Radiant in an Episode 4 act was tier 24, today's Immortal 1.
+    let seasons = &mut mmr["QueueSkills"]["competitive"]["SeasonalInfoBySeasonID"];
+    let mut season = seasons["16118998-4705-5813-86dd-0292a2439d90"].clone();
+    season["SeasonID"] = old_act.into();
+    season["Rank"] = 24.into();
+    season["WinsByTier"] = serde_json::json!({ "23": 4, "24": 2 });
+    seasons[old_act] = season;
+    mock.set_fixture(reqwest::Method::GET, "/content-service/v3/content", content);
+    mock.set_fixture(reqwest::Method::GET, "/mmr/v1/players/*", mmr);
+    let client = mock.client().await.unwrap();
+
+    let mmr = client.mmr(MOCK_PUUID).await.unwrap();
+    let content = client.content().await.unwrap();
+    assert_eq!(mmr.peak_tier(&content), CompetitiveTier::Radiant);
+    let lobby = client.live_lobby().await.unwrap();
+    assert_eq!(lobby.player(MOCK_PUUID).unwrap().peak_tier, CompetitiveTier::Radiant);
+}
+
+#[tokio::test]
+async fn live_lobby_falls_back_to_pregame() {
+    let mock = MockServer::start().await.unwrap();
+    mock.set_status(reqwest::Method::GET, "/core-game/v1/players/*", reqwest::StatusCode::NOT_FOUND);
+    let client = mock.client().await.unwrap();
+
+    let lobby = client.live_lobby().await.unwrap();
+    assert_eq!(lobby.phase, LobbyPhase::Pregame);
+    let me = lobby.player(MOCK_PUUID).unwrap();
+    assert_eq!(me.selection_state, Some(CharacterSelectionState::Locked));
+    assert!(me.skins.is_empty());
+
+    // A selection state this crate doesn't know yet still decodes.
+    let mut pregame: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/pregame_match.json")).unwrap();
+    pregame["AllyTeam"]["Players"][0]["CharacterSelectionState"] = "hovering".into();
+    mock.set_fixture(reqwest::Method::GET, "/pregame/v1/matches/*", pregame);
+    let lobby = client.live_lobby().await.unwrap();
+    assert!(lobby.players.iter().any(|p| p.selection_state == Some(CharacterSelectionState::Unknown)));
+
+    mock.set_status(reqwest::Method::GET, "/pregame/v1/players/*", reqwest::StatusCode::NOT_FOUND);
+    assert!(matches!(client.live_lobby().await, Err(ValorantError::NotInMatch)));
+}
+
+#[tokio::test]
+async fn premade_groups() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let player = client.coregame_player(MOCK_PUUID).await.unwrap();
+    let live = client.coregame_match(&player.match_id).await.unwrap();
+    let premades = Premades::from_coregame(&live);
+    assert_eq!(premades.groups.len(), 2);
+    assert_eq!(premades.on_team("Blue").count(), 1);
+    let mine = premades.group_of(MOCK_PUUID).unwrap();
+    assert_eq!(mine.members.len(), 2);
+    assert_eq!(premades.on_team("Red").next().unwrap().members.len(), 3);
+
+    // Indices don't depend on the order players are listed in.
+    let reversed = Premades::from_players(live.players.iter().rev()
+        .map(|p| (p.puuid.as_str(), p.team_id.as_str(), p.party_id.as_str())));
+    assert_eq!(reversed.index_of(MOCK_PUUID), premades.index_of(MOCK_PUUID));
+
+    let lobby = client.live_lobby().await.unwrap();
+    assert_eq!(lobby.premades.groups.len(), 2);
+    assert_eq!(lobby.players.iter().filter(|p| p.premade_group.is_some()).count(), 5);
+    assert_eq!(lobby.player(MOCK_PUUID).unwrap().premade_group, Some(mine.index));
+
+    let pregame = client.pregame_player(MOCK_PUUID).await.unwrap();
+    let pregame_match = client.pregame_match(&pregame.match_id).await.unwrap();
+    assert_eq!(Premades::from_pregame(&pregame_match).groups.len(), 1);
+}
+
+#[tokio::test]
+async fn phase_tracking() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+    assert_eq!(client.game_phase().await.unwrap().phase, GamePhase::Pregame);
+
+    let (log_watcher, _) = LogWatcher::new();
+    let (tracker, mut changes) = PhaseTracker::new(client);
+    let state = tracker
+        .with_log_events(log_watcher.subscribe())
+        .with_interval(std::time::Duration::from_millis(20))
+        .with_post_game_hold(std::time::Duration::ZERO)
+        .start();
+
+    let change = next(&mut changes).await;
+    assert_eq!((change.previous, change.phase), (GamePhase::NotRunning, GamePhase::Pregame));
+    assert!(change.match_id.is_some());
+
+    mock.set_status(reqwest::Method::GET, "/pregame/v1/players/*", reqwest::StatusCode::NOT_FOUND);
+    let change = next(&mut changes).await;
+    assert_eq!(change.phase, GamePhase::InGame);
+    let match_id = change.match_id;
+
+    // The log reports the end before the coregame session goes away.
+    log_watcher.sender().send(LogEvent::MatchEnded { winning_team: "Blue".into() }).unwrap();
+    let change = next(&mut changes).await;
+    assert_eq!((change.phase, &change.match_id), (GamePhase::PostGame, &match_id));
+
+    mock.set_status(reqwest::Method::GET, "/core-game/v1/players/*", reqwest::StatusCode::NOT_FOUND);
+    let change = next(&mut changes).await;
+    assert_eq!((change.previous, change.phase), (GamePhase::PostGame, GamePhase::Menu));
+    assert_eq!(state.read().await.phase, GamePhase::Menu);
+
+    // Without presence, queueing is read from the GLZ party.
+    mock.set_status(reqwest::Method::GET, "/chat/v4/presences", reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+    let mut party: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/party.json")).unwrap();
+    party["State"] = "MATCHMAKING".into();
+    mock.set_fixture(reqwest::Method::GET, "/parties/v1/parties/*", party);
+    assert_eq!(next(&mut changes).await.phase, GamePhase::Queueing);
+
+    // Presence in the menus wins over the party and skips GLZ entirely.
+    mock.set_fixture(reqwest::Method::GET, "/chat/v4/presences", own_presence("MENUS", "DEFAULT"));
+    assert_eq!(next(&mut changes).await.phase, GamePhase::Menu);
+    let glz_requests = mock.request_count(reqwest::Method::GET, "/pregame/v1/players/*");
+    mock.set_fixture(reqwest::Method::GET, "/chat/v4/presences", own_presence("MENUS", "MATCHMAKING"));
+    assert_eq!(next(&mut changes).await.phase, GamePhase::Queueing);
+    assert_eq!(mock.request_count(reqwest::Method::GET, "/pregame/v1/players/*"), glz_requests);
+}
+
+/// The presences fixture with the user's own session and party state replaced.
+fn own_presence(session_loop_state: &str, party_state: &str) -> serde_json::Value {
+    use base64::Engine;
+    let engine = base64::engine::general_purpose::STANDARD;
+    let mut presences: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/presences.json")).unwrap();
+    let own = presences["presences"].as_array_mut().unwrap().iter_mut()
+        .find(|p| p["puuid"] == MOCK_PUUID).unwrap();
+    let mut private: serde_json::Value = serde_json::from_slice(&engine.decode(own["private"].as_str().unwrap()).unwrap()).unwrap();
+    private["matchPresenceData"]["sessionLoopState"] = session_loop_state.into();
+    private["partyPresenceData"]["partyState"] = party_state.into();
+    own["private"] = engine.encode(private.to_string()).into();
+    presences
+}
+
+#[tokio::test]
+async fn websocket_events() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+    let mut stream = client.subscribe_events(&[events::PRESENCES]);
+    mock.wait_for_subscription(events::PRESENCES).await;
+
+    mock.push_event(events::SESSIONS, "/product-session/v1/external-sessions", "Update", serde_json::json!({}));
+    mock.push_event(events::PRESENCES, "/chat/v4/presences", "Update", serde_json::json!({ "presences": [] }));
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next()).await.unwrap().unwrap();
+    assert_eq!(event.event, events::PRESENCES);
+    assert_eq!(event.uri, "/chat/v4/presences");
+    assert_eq!(event.event_type, LocalEventType::Update);
+    assert!(event.data["presences"].is_array());
+
+    // Resubscribes after the client drops the connection.
+    mock.disconnect_websockets();
+    tokio::time::timeout(std::time::Duration::from_secs(10), mock.wait_for_subscription(events::PRESENCES)).await.unwrap();
+    mock.push_event(events::PRESENCES, "/chat/v4/presences", "Delete", serde_json::Value::Null);
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next()).await.unwrap().unwrap();
+    assert_eq!(event.event_type, LocalEventType::Delete);
+}
+
+#[tokio::test]
+async fn presences() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let presences = client.presences().await.unwrap();
+    assert_eq!(presences.presences.len(), 5);
+    assert_eq!(presences.valorant().count(), 4);
+
+    let friend = presences.presences.iter().find(|p| p.game_name == "sagemain").unwrap();
+    let game = friend.valorant.as_ref().unwrap();
+    assert_eq!(game.session_state(), SessionLoopState::InGame);
+    assert_eq!(game.queue(), &Queue::Competitive);
+    assert_eq!(game.map_name(), Some("Ascent"));
+    assert_eq!(game.score(), Some((7, 5)));
+    assert_eq!(game.party_size(), 2);
+    assert_eq!(game.competitive_tier(), CompetitiveTier::Gold3);
+    assert_eq!(game.summary(), "In a Competitive match on Ascent, 7-5");
+
+    let summaries: Vec<String> = presences.valorant().map(|(_, v)| v.summary()).collect();
+    assert!(summaries.contains(&"In agent select (Unrated)".to_string()));
+    assert!(summaries.contains(&"Queueing for Swiftplay".to_string()));
+
+    let league = presences.presences.iter().find(|p| p.product == "league_of_legends").unwrap();
+    assert!(league.valorant.is_none());
+
+    let mut updates = Box::pin(client.presence_updates());
+    mock.wait_for_subscription(events::PRESENCES).await;
+    let update: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/presences.json")).unwrap();
+    mock.push_event(events::PRESENCES, "/chat/v4/presences", "Update", update);
+    let first = tokio::time::timeout(std::time::Duration::from_secs(5), updates.next()).await.unwrap().unwrap();
+    assert_eq!(first.puuid, MOCK_PUUID);
+    assert!(first.valorant.is_some());
+}
+
+#[tokio::test]
+async fn friends() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let friends = client.friends().await.unwrap();
+    assert_eq!(friends.friends.len(), 5);
+    let duo = friends.find_by_riot_id("SAGEMAIN", "1337").unwrap();
+    assert_eq!(duo.riot_id(), "sagemain#1337");
+    assert!(duo.last_online.is_none());
+
+    let requests = client.friend_requests().await.unwrap();
+    assert_eq!(requests.incoming().next().unwrap().game_name, "entryfragger");
+    assert_eq!(requests.outgoing().next().unwrap().game_name, "clutchqueen");
+
+    client.send_friend_request("newfriend", "EUW").await.unwrap();
+    client.remove_friend(&duo.puuid).await.unwrap();
+
+    assert_eq!(client.lookup_player("sagemain", "1337").await.unwrap(), duo.puuid);
+    assert_eq!(client.lookup_player("houdyxx", "altf4").await.unwrap(), MOCK_PUUID);
+    assert!(matches!(
+        client.lookup_player("stranger", "0000").await,
+        Err(ValorantError::ApiError { status: 404, .. })
+    ));
+
+    mock.set_status(reqwest::Method::GET, "/chat/v4/friends", reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(client.lookup_player("houdyxx", "altf4").await.unwrap(), MOCK_PUUID);
+}
+
+#[tokio::test]
+async fn chat() {
+    let mock = MockServer::start().await.unwrap();
+    let client = mock.client().await.unwrap();
+
+    let conversations = client.conversations().await.unwrap();
+    let channels: Vec<ChatChannel> = conversations.conversations.iter().map(|c| c.channel()).collect();
+    assert_eq!(channels, [ChatChannel::Party, ChatChannel::Pregame, ChatChannel::Team, ChatChannel::All, ChatChannel::Direct]);
+
+    let all = conversations.channel(ChatChannel::All).unwrap();
+    let history = client.chat_history(&all.cid).await.unwrap();
+    assert_eq!(history.len(), 2);
+    assert!(history.windows(2).all(|w| w[0].time <= w[1].time));
+    assert_eq!(history[0].sender(), "hotshot#PHX");
+    assert_eq!(history[1].puuid, MOCK_PUUID);
+
+    let sent = client.send_message(&all.cid, "gg").await.unwrap();
+    assert_eq!((sent.body.as_str(), sent.channel()), ("gg", ChatChannel::All));
+
+    let mut messages = Box::pin(client.chat_messages());
+    mock.wait_for_subscription(events::CHAT_MESSAGES).await;
+    let update: serde_json::Value = serde_json::from_str(include_str!("../src/mock/fixtures/chat_messages.json")).unwrap();
+    // Read-state changes arrive as updates and must not replay old messages.
+    let mut read = update.clone();
+    read["messages"][0]["body"] = "already seen".into();
+    read["messages"][0]["read"] = true.into();
+    mock.push_event(events::CHAT_MESSAGES, "/chat/v6/messages", "Update", read);
+    mock.push_event(events::CHAT_MESSAGES, "/chat/v6/messages", "Create", update);
+    let first = tokio::time::timeout(std::time::Duration::from_secs(5), messages.next()).await.unwrap().unwrap();
+    assert_eq!(first.channel(), ChatChannel::Team);
+    assert_eq!(first.game_name, "arrowz");
+    assert_ne!(first.body, "already seen");
+}
+
+async fn next(changes: &mut tokio::sync::broadcast::Receiver<PhaseChange>) -> PhaseChange {
+    tokio::time::timeout(std::time::Duration::from_secs(5), changes.recv()).await.unwrap().unwrap()
+}
+
+async fn next_state(states: &mut tokio::sync::broadcast::Receiver<ConnectionState>) -> ConnectionState {
+    tokio::time::timeout(std::time::Duration::from_secs(5), states.recv()).await.unwrap().unwrap()
+}